@@ -1,7 +1,7 @@
 use crate::
 {
     components::{footer::Footer, nav::Nav},
-    contexts::user_context::UserProvider,
+    contexts::{config_context::ConfigProvider, user_context::UserProvider},
     router::{switch, AppRoute},
 };
 use i18nrs::yew::{I18nProvider, I18nProviderConfig};
@@ -26,6 +26,9 @@ pub fn app() -> Html
                     "status": "Status",
                     "created_on": "Created on: {date}",
                     "back_to_home": "Back to home",
+                    "export_csv": "Export CSV",
+                    "export_json": "Export JSON",
+                    "close": "Close",
                     "status_running": "Running",
                     "status_exited": "Exited",
                     "status_stopped": "Stopped",
@@ -43,6 +46,7 @@ pub fn app() -> Html
                 },
                 "nav": {
                     "home": "Home",
+                    "templates": "Templates",
                     "admin": "Admin",
                     "logout": "Logout"
                 },
@@ -221,6 +225,7 @@ pub fn app() -> Html
                     "welcome": "Welcome, {name}!",
                     "description": "Your application deployment center.",
                     "create_project_button": "New project or database",
+                    "templates_button": "Start from a template",
                     "owned_projects_title": "My Projects & Databases",
                     "participating_projects_title": "My participations",
                     "empty_state_owned": "You don't own any projects or databases yet.",
@@ -235,7 +240,7 @@ pub fn app() -> Html
                     "description_direct": "For advanced users. Deploy a service from a **public** Docker image. Note: Private images from `ghcr.io` are not supported in this mode.",
                     "name_label": "Project name",
                     "name_placeholder": "my-awesome-app",
-                    "name_help": "Will be used for the URL (e.g., my-awesome-app.garageisep.com). Only letters, numbers, and hyphens.",
+                    "name_help": "Will be used for the URL (e.g., my-awesome-app.garageisep.com). Only lowercase letters, numbers, and hyphens.",
                     "github_repo_url_label": "GitHub repository URL",
                     "github_repo_url_placeholder": "https://github.com/user/my-project",
                     "image_label": "Docker image URL",
@@ -252,10 +257,52 @@ pub fn app() -> Html
                     "volume_path_help": "Path inside the container to persist.",
                     "env_vars_label": "Environment Variables (Optional)",
                     "env_vars_help": "One variable per line, in KEY=VALUE format.",
+                    "validation_required": "This field is required.",
+                    "validation_name_too_long": "The name must be at most 63 characters long.",
+                    "validation_name_invalid_chars": "Use only lowercase letters, digits and hyphens.",
+                    "validation_name_hyphen_edge": "The name cannot start or end with a hyphen.",
+                    "validation_image_uppercase": "Image repository names must be lowercase.",
+                    "validation_image_invalid_registry": "The registry host is invalid (expected e.g. ghcr.io or registry.example.com:5000).",
+                    "validation_image_invalid_path": "The image path is invalid. Use lowercase letters, digits, and single separators (. _ -) between them.",
+                    "validation_image_name_too_long": "The image name must be at most 255 characters long.",
+                    "validation_image_invalid_tag": "The tag is invalid. Use up to 128 letters, digits, underscores, periods or hyphens.",
+                    "validation_image_invalid_digest": "The digest is invalid (expected e.g. sha256:<hex>).",
+                    "validation_env_missing_separator": "Line {line}: expected KEY=VALUE.",
+                    "validation_env_invalid_key": "Line {line}: keys may only contain letters, digits and underscores, and cannot start with a digit.",
+                    "validation_env_duplicate_key": "Line {line}: this key is already defined above.",
+                    "validation_env_value_required": "Line {line}: values are not saved, enter this one again.",
+                    "validation_volume_not_absolute": "The path must be absolute (start with /).",
+                    "validation_volume_root": "The container root cannot be used as a volume.",
+                    "validation_volume_relative_segment": "The path cannot contain . or .. segments.",
+                    "validation_volume_invalid_chars": "The path cannot contain spaces, colons or commas.",
+                    "import_manifest_button": "Import hangar.json / hangar.toml",
+                    "import_manifest_help": "Pre-fill the form from a manifest exported from another project.",
+                    "import_manifest_success": "Form pre-filled from {file}. Fill in the values of the environment variables before deploying.",
+                    "template_applied": "Form pre-filled from the \"{template}\" template. Create your own copy of the starter repository on GitHub, then select it below.",
+                    "template_copy_repo_button": "Copy the starter repository",
+                    "step_source": "Source",
+                    "step_configuration": "Configuration",
+                    "step_collaborators": "Collaborators",
+                    "step_review": "Review",
+                    "next_button": "Next",
+                    "back_button": "Back",
+                    "review_method": "Deployment method",
+                    "review_url": "URL",
+                    "review_edit": "Edit",
+                    "review_none": "None",
+                    "review_yes": "Yes",
+                    "review_no": "No",
+                    "draft_restored": "Your unfinished project was restored. Environment variable values are not saved in drafts: fill them in again before deploying.",
+                    "discard_draft_button": "Start over",
                     "database_tab": "Database Only",
                     "description_database": "Create a standalone MariaDB database, without linking it to a project.",
                     "create_db_checkbox": "Also create and link a new database",
                     "github_branch_help": "Leave empty to use the default branch.",
+                    "github_repo_search_placeholder": "Search your repositories...",
+                    "github_repos_unavailable": "Your repositories could not be listed ({error}), type the URL instead.",
+                    "github_repo_not_found": "Pick a repository from the list.",
+                    "github_branch_not_found": "This branch does not exist in the repository.",
+                    "github_root_dir_not_found": "This directory does not exist on the branch.",
                     "github_branch_label": "GitHub branch (Optional)",
                     "github_root_dir_label": "Root Directory (Optional)",
                     "github_root_dir_help": "Relative path to the folder containing index.php. Example: /src/public. Leave empty if index.php is at the project root.",
@@ -282,6 +329,15 @@ pub fn app() -> Html
                     "deployment_stage_cleanup": "Cleaning up",
                     "deployment_stage_completed": "Deployment complete",
                     "deployment_stage_failed": "Deployment failed",
+                    "deployment_total_time": "Total time: {duration}",
+                    "deployment_stage_cancelled": "Deployment cancelled",
+                    "deployment_cancelled_message": "The deployment was cancelled before it finished.",
+                    "cancel_deployment_button": "Cancel deployment",
+                    "cancel_deployment_button_loading": "Cancelling...",
+                    "confirm_cancel_deployment": "Cancel this deployment? The work done so far will be lost.",
+                    "build_output_title": "Build output",
+                    "build_output_lines": "{count} lines",
+                    "build_output_truncated": "{count} earlier lines were dropped.",
                     "deployment_redirecting": "Redirecting to dashboard..."
                 },
                 "project_dashboard": {
@@ -311,6 +367,90 @@ pub fn app() -> Html
                     "restart_success": "Project restarted successfully!",
                     "fetch_logs_button": "Fetch logs",
                     "fetch_logs_loading": "Loading...",
+                    "follow_logs_button": "Follow",
+                    "unfollow_logs_button": "Stop following",
+                    "pause_logs_button": "Pause",
+                    "resume_logs_button": "Resume",
+                    "logs_waiting": "Waiting for new log lines...",
+                    "logs_paused_hint": "Paused: {count} new lines buffered.",
+                    "logs_search_placeholder": "Search logs...",
+                    "logs_regex": "Regex",
+                    "logs_level_error": "Errors",
+                    "logs_level_warn": "Warnings",
+                    "logs_level_info": "Info",
+                    "logs_from": "From",
+                    "logs_to": "To",
+                    "logs_invalid_regex": "Invalid regular expression: {error}",
+                    "logs_visible_count": "Showing {visible} of {total} lines",
+                    "logs_no_match": "No log line matches the current filters.",
+                    "logs_download_button": "Download .log",
+                    "logs_fields_placeholder": "Filter by field, e.g. request_id=42",
+                    "logs_fields_count": "{count} fields",
+                    "logs_fields_close": "Close",
+                    "metrics_history_title": "History",
+                    "metrics_range_minutes": "{minutes} min",
+                    "metrics_history_empty": "Not enough samples yet to draw a chart.",
+                    "metrics_network_rx": "Network in:",
+                    "metrics_network_tx": "Network out:",
+                    "metrics_block_read": "Disk read:",
+                    "metrics_block_write": "Disk write:",
+                    "metrics_pids": "Processes:",
+                    "card_title_alerts": "Alerts",
+                    "alerts_description": "Rules are saved in this browser. You are notified while this page is open.",
+                    "alerts_empty": "No alert rule yet.",
+                    "alert_metric_cpu": "CPU",
+                    "alert_metric_memory": "RAM (% of limit)",
+                    "alert_rule_description": "{metric} above {threshold}% for {duration} s",
+                    "alert_threshold_label": "Threshold (%)",
+                    "alert_duration_label": "For (seconds)",
+                    "alert_add_button": "Add rule",
+                    "alert_delete_button": "Delete",
+                    "alert_invalid_rule": "The threshold must be a positive number and the duration a whole number of seconds.",
+                    "alert_current_value": "Current value: {value}%",
+                    "alert_notification_title": "Hangar alert: {name}",
+                    "card_title_deployments": "Deployments",
+                    "deployments_empty": "This project has not been deployed yet.",
+                    "deployment_trigger": "Trigger",
+                    "deployment_triggered_by": "Triggered by",
+                    "deployment_digest": "Image digest",
+                    "deployment_started_at": "Started",
+                    "deployment_finished_at": "Finished",
+                    "deployment_result": "Result",
+                    "deployment_running": "Running",
+                    "deployment_failed_at": "Failed during stage: {stage}",
+                    "deployment_trigger_create": "Creation",
+                    "deployment_trigger_image_update": "Image update",
+                    "deployment_trigger_rebuild": "Rebuild",
+                    "deployment_trigger_env_change": "Environment change",
+                    "deployment_trigger_rollback": "Rollback",
+                    "deployment_trigger_push": "Push to GitHub",
+                    "deployment_trigger_unknown": "Other",
+                    "rollback_button": "Roll back to this version",
+                    "confirm_rollback": "Roll '{name}' back to image {digest}? The environment variables will be restored to their values at the time of that deployment.",
+                    "card_title_security_scan": "Security scan",
+                    "scan_unavailable": "No scan report is available for the deployed image.",
+                    "scan_scanned_at": "Scanned on {date}",
+                    "scan_no_vulnerabilities": "No known vulnerabilities were found in this image.",
+                    "scan_all_filtered": "Every vulnerability is hidden by the severity filters.",
+                    "scan_severity": "Severity",
+                    "scan_vulnerability": "Vulnerability",
+                    "scan_package": "Package",
+                    "scan_installed_version": "Installed",
+                    "scan_fixed_version": "Fixed in",
+                    "scan_no_fix": "No fix yet",
+                    "severity_critical": "Critical",
+                    "severity_high": "High",
+                    "severity_medium": "Medium",
+                    "severity_low": "Low",
+                    "severity_negligible": "Negligible",
+                    "severity_unknown": "Unknown",
+                    "auto_deploy_label": "Redeploy automatically on every push to {branch}",
+                    "webhook_last_delivery": "Last push received",
+                    "webhook_no_delivery": "No push has been received from GitHub yet.",
+                    "webhook_status_deployed": "Deployed",
+                    "webhook_status_ignored": "Ignored",
+                    "webhook_status_rejected": "Rejected",
+                    "webhook_resulting_deployment": "Resulting deployment:",
                     "update_image_description": "Deploy a new version of your application by providing a new Docker image URL.",
                     "confirm_update_image": "Are you sure? Updating the image for '{name}' will take a few moments.",
                     "update_image_button": "Update image",
@@ -333,9 +473,15 @@ pub fn app() -> Html
                     "save_and_restart_button": "Save & Restart",
                     "save_and_restart_button_loading": "Saving...",
                     "persistent_volume_label": "Persistent Volume",
+                    "export_manifest_label": "Export configuration:",
                     "deployment_starting": "Deployment starting...",
                     "deployment_updating": "Update in progress",
-                    "deployment_complete": "Update completed successfully!"
+                    "deployment_complete": "Update completed successfully!",
+                    "live_connecting": "Connecting to live updates...",
+                    "live_connected": "Live",
+                    "live_reconnecting": "Reconnecting (attempt {attempt})...",
+                    "live_failed": "Live updates unavailable",
+                    "live_reconnect": "Reconnect"
                 },
                 "database": {
                     "title": "Database",
@@ -365,7 +511,31 @@ pub fn app() -> Html
                     "all_projects_title": "All projects",
                     "all_databases_title": "Databases",
                     "no_databases": "No databases found.",
-                    "global_metrics_title": "Global metrics"
+                    "global_metrics_title": "Global metrics",
+                    "top_consumers_title": "Top consumers",
+                    "top_consumers_empty": "No running project.",
+                    "top_consumers_project": "Project",
+                    "top_consumers_cpu": "CPU",
+                    "top_consumers_memory": "Memory",
+                    "top_consumers_memory_percent": "Memory (% of limit)"
+                },
+                "templates": {
+                    "title": "Project templates",
+                    "description": "Not sure how to lay out your project for Hangar? Start from one of these presets: each comes with a starter repository and the settings it needs.",
+                    "start_from_scratch": "Start from scratch",
+                    "use_button": "Use this template",
+                    "starter_repo": "Starter repository",
+                    "env_vars": "Environment variables",
+                    "volume": "Persistent volume",
+                    "database": "Database",
+                    "static_nginx_name": "Static site (nginx)",
+                    "static_nginx_description": "Plain HTML, CSS and JavaScript served by nginx. Ideal for a portfolio or a landing page.",
+                    "node_express_name": "Node.js / Express",
+                    "node_express_description": "A minimal Express API with a persistent folder for user uploads.",
+                    "flask_mysql_name": "Flask + MySQL",
+                    "flask_mysql_description": "A Python Flask app connected to a MySQL database created alongside the project. Set SECRET_KEY before deploying.",
+                    "rust_axum_name": "Rust / axum",
+                    "rust_axum_description": "An axum web server built in a multi-stage Dockerfile for a small final image."
                 },
                 "errors": {
                     "PROJECT_NAME_TAKEN": "This project name is already taken.",
@@ -380,6 +550,9 @@ pub fn app() -> Html
                     "OWNER_CANNOT_BE_PARTICIPANT": "The project owner cannot be added as a participant.",
                     "GITHUB_ACCOUNT_NOT_LINKED": "Your GitHub account is not linked. You must link it to deploy from a repository.",
                     "GITHUB_REPO_NOT_ACCESSIBLE": "The Hangar App does not have access to this repository. Please update your installation permissions. Then try again.",
+                    "FORM_INVALID": "Some fields are invalid. Please fix the highlighted fields.",
+                    "INVALID_MANIFEST": "The manifest could not be read. Use a hangar.json or hangar.toml file exported from Hangar.",
+                    "GITHUB_SOURCE_INVALID": "The repository, branch or directory does not match what GitHub reports. Please fix the highlighted fields.",
                     "GITHUB_PACKAGE_NOT_PUBLIC": "Direct deployment from ghcr.io failed. Please ensure your package is set to 'Public'.",
                     "DEFAULT": "An unexpected error occurred. Please contact an administrator.",
                     "DATABASE_ALREADY_EXISTS": "You already own a database. Only one is allowed per user.",
@@ -400,6 +573,9 @@ pub fn app() -> Html
                     "status": "Statut",
                     "created_on": "Créé le : {date}",
                     "back_to_home": "Retour à l'accueil",
+                    "export_csv": "Exporter en CSV",
+                    "export_json": "Exporter en JSON",
+                    "close": "Fermer",
                     "status_running": "En cours",
                     "status_exited": "Terminé",
                     "status_stopped": "Arrêté",
@@ -417,6 +593,7 @@ pub fn app() -> Html
                 },
                 "nav": {
                     "home": "Accueil",
+                    "templates": "Modèles",
                     "admin": "Admin",
                     "logout": "Déconnexion"
                 },
@@ -595,6 +772,7 @@ pub fn app() -> Html
                     "welcome": "Bienvenue, {name} !",
                     "description": "Votre centre de déploiement d'applications.",
                     "create_project_button": "Nouveau projet ou BDD",
+                    "templates_button": "Partir d'un modèle",
                     "owned_projects_title": "Mes Projets & Bases de Données",
                     "participating_projects_title": "Mes participations",
                     "empty_state_owned": "Vous n'avez encore aucun projet ni base de données.",
@@ -609,7 +787,7 @@ pub fn app() -> Html
                     "description_direct": "Pour les utilisateurs avancés. Déployez un service à partir d'une image Docker **publique**. Note : les images privées de `ghcr.io` ne sont pas supportées dans ce mode.",
                     "name_label": "Nom du projet",
                     "name_placeholder": "mon-app-geniale",
-                    "name_help": "Sera utilisé pour l'URL (ex: mon-app-geniale.garageisep.com). Lettres, chiffres et tirets uniquement.",
+                    "name_help": "Sera utilisé pour l'URL (ex: mon-app-geniale.garageisep.com). Lettres minuscules, chiffres et tirets uniquement.",
                     "github_repo_url_label": "URL du dépôt GitHub",
                     "github_repo_url_placeholder": "https://github.com/user/mon-projet",
                     "image_label": "URL de l'image Docker",
@@ -626,10 +804,52 @@ pub fn app() -> Html
                     "volume_path_help": "Chemin à l'intérieur du conteneur à persister.",
                     "env_vars_label": "Variables d'environnement (facultatif)",
                     "env_vars_help": "Une variable par ligne, au format KEY=VALUE.",
+                    "validation_required": "Ce champ est obligatoire.",
+                    "validation_name_too_long": "Le nom doit comporter au plus 63 caractères.",
+                    "validation_name_invalid_chars": "Utilisez uniquement des lettres minuscules, des chiffres et des tirets.",
+                    "validation_name_hyphen_edge": "Le nom ne peut pas commencer ni se terminer par un tiret.",
+                    "validation_image_uppercase": "Les noms de dépôt d'image doivent être en minuscules.",
+                    "validation_image_invalid_registry": "L'hôte du registre est invalide (ex. attendu : ghcr.io ou registry.example.com:5000).",
+                    "validation_image_invalid_path": "Le chemin de l'image est invalide. Utilisez des lettres minuscules, des chiffres et des séparateurs simples (. _ -) entre eux.",
+                    "validation_image_name_too_long": "Le nom de l'image doit comporter au plus 255 caractères.",
+                    "validation_image_invalid_tag": "Le tag est invalide. Utilisez jusqu'à 128 lettres, chiffres, tirets bas, points ou tirets.",
+                    "validation_image_invalid_digest": "Le digest est invalide (ex. attendu : sha256:<hex>).",
+                    "validation_env_missing_separator": "Ligne {line} : format KEY=VALUE attendu.",
+                    "validation_env_invalid_key": "Ligne {line} : les clés ne peuvent contenir que des lettres, chiffres et tirets bas, et ne peuvent pas commencer par un chiffre.",
+                    "validation_env_duplicate_key": "Ligne {line} : cette clé est déjà définie plus haut.",
+                    "validation_env_value_required": "Ligne {line} : les valeurs ne sont pas enregistrées, saisissez à nouveau celle-ci.",
+                    "validation_volume_not_absolute": "Le chemin doit être absolu (commencer par /).",
+                    "validation_volume_root": "La racine du conteneur ne peut pas être utilisée comme volume.",
+                    "validation_volume_relative_segment": "Le chemin ne peut pas contenir de segments . ou ..",
+                    "validation_volume_invalid_chars": "Le chemin ne peut pas contenir d'espaces, de deux-points ni de virgules.",
+                    "import_manifest_button": "Importer hangar.json / hangar.toml",
+                    "import_manifest_help": "Pré-remplir le formulaire à partir d'un manifeste exporté d'un autre projet.",
+                    "import_manifest_success": "Formulaire pré-rempli depuis {file}. Renseignez les valeurs des variables d'environnement avant de déployer.",
+                    "template_applied": "Formulaire pré-rempli avec le modèle « {template} ». Créez votre propre copie du dépôt de départ sur GitHub, puis sélectionnez-la ci-dessous.",
+                    "template_copy_repo_button": "Copier le dépôt de départ",
+                    "step_source": "Source",
+                    "step_configuration": "Configuration",
+                    "step_collaborators": "Collaborateurs",
+                    "step_review": "Récapitulatif",
+                    "next_button": "Suivant",
+                    "back_button": "Retour",
+                    "review_method": "Méthode de déploiement",
+                    "review_url": "URL",
+                    "review_edit": "Modifier",
+                    "review_none": "Aucun",
+                    "review_yes": "Oui",
+                    "review_no": "Non",
+                    "draft_restored": "Votre projet en cours a été restauré. Les valeurs des variables d'environnement ne sont pas enregistrées dans les brouillons : renseignez-les à nouveau avant de déployer.",
+                    "discard_draft_button": "Recommencer",
                     "database_tab": "Base de données seule",
                     "description_database": "Créez une base de données MariaDB autonome, sans la lier à un projet.",
                     "create_db_checkbox": "Créer et lier également une nouvelle base de données",
                     "github_branch_help": "Laissez vide pour utiliser la branche par défaut.",
+                    "github_repo_search_placeholder": "Rechercher dans vos dépôts...",
+                    "github_repos_unavailable": "Impossible de lister vos dépôts ({error}), saisissez l'URL à la place.",
+                    "github_repo_not_found": "Choisissez un dépôt dans la liste.",
+                    "github_branch_not_found": "Cette branche n'existe pas dans le dépôt.",
+                    "github_root_dir_not_found": "Ce dossier n'existe pas sur la branche.",
                     "github_branch_label": "Branche GitHub (facultatif)",
                     "github_root_dir_label": "Dossier Racine (facultatif)",
                     "github_root_dir_help": "Chemin relatif vers le dossier contenant index.php. Ex: /src/public. Laissez vide si index.php est à la racine.",
@@ -656,6 +876,15 @@ pub fn app() -> Html
                     "deployment_stage_cleanup": "Nettoyage",
                     "deployment_stage_completed": "Déploiement terminé",
                     "deployment_stage_failed": "Échec du déploiement",
+                    "deployment_total_time": "Durée totale : {duration}",
+                    "deployment_stage_cancelled": "Déploiement annulé",
+                    "deployment_cancelled_message": "Le déploiement a été annulé avant la fin.",
+                    "cancel_deployment_button": "Annuler le déploiement",
+                    "cancel_deployment_button_loading": "Annulation...",
+                    "confirm_cancel_deployment": "Annuler ce déploiement ? Le travail déjà effectué sera perdu.",
+                    "build_output_title": "Sortie du build",
+                    "build_output_lines": "{count} lignes",
+                    "build_output_truncated": "{count} lignes plus anciennes ont été supprimées.",
                     "deployment_redirecting": "Redirection..."
                 },
                 "project_dashboard": {
@@ -685,6 +914,90 @@ pub fn app() -> Html
                     "restart_success": "Projet redémarré avec succès !",
                     "fetch_logs_button": "Récupérer les logs",
                     "fetch_logs_loading": "Chargement...",
+                    "follow_logs_button": "Suivre",
+                    "unfollow_logs_button": "Arrêter de suivre",
+                    "pause_logs_button": "Pause",
+                    "resume_logs_button": "Reprendre",
+                    "logs_waiting": "En attente de nouvelles lignes de log...",
+                    "logs_paused_hint": "En pause : {count} nouvelles lignes en attente.",
+                    "logs_search_placeholder": "Rechercher dans les logs...",
+                    "logs_regex": "Regex",
+                    "logs_level_error": "Erreurs",
+                    "logs_level_warn": "Avertissements",
+                    "logs_level_info": "Info",
+                    "logs_from": "Du",
+                    "logs_to": "Au",
+                    "logs_invalid_regex": "Expression régulière invalide : {error}",
+                    "logs_visible_count": "{visible} lignes affichées sur {total}",
+                    "logs_no_match": "Aucune ligne de log ne correspond aux filtres actuels.",
+                    "logs_download_button": "Télécharger en .log",
+                    "logs_fields_placeholder": "Filtrer par champ, ex. request_id=42",
+                    "logs_fields_count": "{count} champs",
+                    "logs_fields_close": "Fermer",
+                    "metrics_history_title": "Historique",
+                    "metrics_range_minutes": "{minutes} min",
+                    "metrics_history_empty": "Pas encore assez de mesures pour tracer un graphique.",
+                    "metrics_network_rx": "Réseau entrant :",
+                    "metrics_network_tx": "Réseau sortant :",
+                    "metrics_block_read": "Lecture disque :",
+                    "metrics_block_write": "Écriture disque :",
+                    "metrics_pids": "Processus :",
+                    "card_title_alerts": "Alertes",
+                    "alerts_description": "Les règles sont enregistrées dans ce navigateur. Vous êtes notifié tant que cette page est ouverte.",
+                    "alerts_empty": "Aucune règle d'alerte pour le moment.",
+                    "alert_metric_cpu": "CPU",
+                    "alert_metric_memory": "RAM (% de la limite)",
+                    "alert_rule_description": "{metric} au-dessus de {threshold} % pendant {duration} s",
+                    "alert_threshold_label": "Seuil (%)",
+                    "alert_duration_label": "Pendant (secondes)",
+                    "alert_add_button": "Ajouter la règle",
+                    "alert_delete_button": "Supprimer",
+                    "alert_invalid_rule": "Le seuil doit être un nombre positif et la durée un nombre entier de secondes.",
+                    "alert_current_value": "Valeur actuelle : {value} %",
+                    "alert_notification_title": "Alerte Hangar : {name}",
+                    "card_title_deployments": "Déploiements",
+                    "deployments_empty": "Ce projet n'a pas encore été déployé.",
+                    "deployment_trigger": "Déclencheur",
+                    "deployment_triggered_by": "Déclenché par",
+                    "deployment_digest": "Digest de l'image",
+                    "deployment_started_at": "Début",
+                    "deployment_finished_at": "Fin",
+                    "deployment_result": "Résultat",
+                    "deployment_running": "En cours",
+                    "deployment_failed_at": "Échec à l'étape : {stage}",
+                    "deployment_trigger_create": "Création",
+                    "deployment_trigger_image_update": "Mise à jour de l'image",
+                    "deployment_trigger_rebuild": "Reconstruction",
+                    "deployment_trigger_env_change": "Changement d'environnement",
+                    "deployment_trigger_rollback": "Retour arrière",
+                    "deployment_trigger_push": "Push sur GitHub",
+                    "deployment_trigger_unknown": "Autre",
+                    "rollback_button": "Revenir à cette version",
+                    "confirm_rollback": "Revenir à l'image {digest} pour '{name}' ? Les variables d'environnement seront restaurées à leurs valeurs lors de ce déploiement.",
+                    "card_title_security_scan": "Analyse de sécurité",
+                    "scan_unavailable": "Aucun rapport d'analyse n'est disponible pour l'image déployée.",
+                    "scan_scanned_at": "Analysée le {date}",
+                    "scan_no_vulnerabilities": "Aucune vulnérabilité connue n'a été trouvée dans cette image.",
+                    "scan_all_filtered": "Toutes les vulnérabilités sont masquées par les filtres de sévérité.",
+                    "scan_severity": "Sévérité",
+                    "scan_vulnerability": "Vulnérabilité",
+                    "scan_package": "Paquet",
+                    "scan_installed_version": "Installée",
+                    "scan_fixed_version": "Corrigée en",
+                    "scan_no_fix": "Pas encore de correctif",
+                    "severity_critical": "Critique",
+                    "severity_high": "Élevée",
+                    "severity_medium": "Moyenne",
+                    "severity_low": "Faible",
+                    "severity_negligible": "Négligeable",
+                    "severity_unknown": "Inconnue",
+                    "auto_deploy_label": "Redéployer automatiquement à chaque push sur {branch}",
+                    "webhook_last_delivery": "Dernier push reçu",
+                    "webhook_no_delivery": "Aucun push n'a encore été reçu de GitHub.",
+                    "webhook_status_deployed": "Déployé",
+                    "webhook_status_ignored": "Ignoré",
+                    "webhook_status_rejected": "Rejeté",
+                    "webhook_resulting_deployment": "Déploiement déclenché :",
                     "update_image_description": "Déployez une nouvelle version de votre application en fournissant une nouvelle URL d'image Docker.",
                     "confirm_update_image": "Êtes-vous sûr ? La mise à jour de l'image pour '{name}' prendra quelques instants.",
                     "update_image_button": "Mettre à jour l'image",
@@ -707,9 +1020,15 @@ pub fn app() -> Html
                     "save_and_restart_button": "Sauvegarder & Redémarrer",
                     "save_and_restart_button_loading": "Sauvegarde...",
                     "persistent_volume_label": "Volume Persistant",
+                    "export_manifest_label": "Exporter la configuration :",
                     "deployment_starting": "Déploiement en cours de démarrage...",
                     "deployment_updating": "Mise à jour en cours",
-                    "deployment_complete": "Mise à jour terminée avec succès !"
+                    "deployment_complete": "Mise à jour terminée avec succès !",
+                    "live_connecting": "Connexion au direct...",
+                    "live_connected": "En direct",
+                    "live_reconnecting": "Reconnexion (tentative {attempt})...",
+                    "live_failed": "Direct indisponible",
+                    "live_reconnect": "Reconnecter"
                 },
                 "database": {
                     "title": "Base de Données",
@@ -739,7 +1058,31 @@ pub fn app() -> Html
                     "all_projects_title": "Tous les projets",
                     "all_databases_title": "Bases de données",
                     "no_databases": "Aucune base de données trouvée.",
-                    "global_metrics_title": "Métriques globales"
+                    "global_metrics_title": "Métriques globales",
+                    "top_consumers_title": "Plus gros consommateurs",
+                    "top_consumers_empty": "Aucun projet en cours d'exécution.",
+                    "top_consumers_project": "Projet",
+                    "top_consumers_cpu": "CPU",
+                    "top_consumers_memory": "Mémoire",
+                    "top_consumers_memory_percent": "Mémoire (% de la limite)"
+                },
+                "templates": {
+                    "title": "Modèles de projet",
+                    "description": "Vous ne savez pas comment organiser votre projet pour Hangar ? Partez d'un de ces modèles : chacun fournit un dépôt de départ et les réglages nécessaires.",
+                    "start_from_scratch": "Partir de zéro",
+                    "use_button": "Utiliser ce modèle",
+                    "starter_repo": "Dépôt de départ",
+                    "env_vars": "Variables d'environnement",
+                    "volume": "Volume persistant",
+                    "database": "Base de données",
+                    "static_nginx_name": "Site statique (nginx)",
+                    "static_nginx_description": "Du HTML, CSS et JavaScript servis par nginx. Idéal pour un portfolio ou une page vitrine.",
+                    "node_express_name": "Node.js / Express",
+                    "node_express_description": "Une API Express minimale avec un dossier persistant pour les fichiers envoyés par les utilisateurs.",
+                    "flask_mysql_name": "Flask + MySQL",
+                    "flask_mysql_description": "Une application Python Flask reliée à une base MySQL créée avec le projet. Renseignez SECRET_KEY avant de déployer.",
+                    "rust_axum_name": "Rust / axum",
+                    "rust_axum_description": "Un serveur web axum construit avec un Dockerfile multi-étapes pour une image finale légère."
                 },
                 "errors": {
                     "PROJECT_NAME_TAKEN": "Ce nom de projet est déjà utilisé.",
@@ -754,6 +1097,9 @@ pub fn app() -> Html
                     "OWNER_CANNOT_BE_PARTICIPANT": "Le propriétaire du projet ne peut pas être ajouté comme participant.",
                     "GITHUB_ACCOUNT_NOT_LINKED": "Votre compte GitHub n'est pas lié. Vous devez le lier pour pouvoir déployer depuis un dépôt.",
                     "GITHUB_REPO_NOT_ACCESSIBLE": "L'application Hangar n'a pas accès à ce dépôt. Veuillez mettre à jour les permissions de votre installation. Puis réessayez.",
+                    "FORM_INVALID": "Certains champs sont invalides. Corrigez les champs signalés.",
+                    "INVALID_MANIFEST": "Le manifeste n'a pas pu être lu. Utilisez un fichier hangar.json ou hangar.toml exporté depuis Hangar.",
+                    "GITHUB_SOURCE_INVALID": "Le dépôt, la branche ou le dossier ne correspond pas à ce que GitHub indique. Corrigez les champs signalés.",
                     "GITHUB_PACKAGE_NOT_PUBLIC": "Le déploiement direct depuis ghcr.io a échoué. Veuillez vous assurer que votre paquet est bien en mode 'Public'.",
                     "DEFAULT": "Une erreur inattendue est survenue. Veuillez contacter un administrateur.",
                     "DATABASE_ALREADY_EXISTS": "Vous possédez déjà une base de données. Une seule est autorisée par utilisateur.",
@@ -790,17 +1136,19 @@ pub fn app() -> Html
     html! 
     {
         <I18nProvider ..config>
-            <UserProvider>
-                <BrowserRouter>
-                    <div style="display: flex; flex-direction: column; min-height: 100vh;">
-                        <Nav />
-                        <main style="flex-grow: 1;">
-                            <Switch<AppRoute> render={switch} />
-                        </main>
-                        <Footer />
-                    </div>
-                </BrowserRouter>
-            </UserProvider>
+            <ConfigProvider>
+                <UserProvider>
+                    <BrowserRouter>
+                        <div style="display: flex; flex-direction: column; min-height: 100vh;">
+                            <Nav />
+                            <main style="flex-grow: 1;">
+                                <Switch<AppRoute> render={switch} />
+                            </main>
+                            <Footer />
+                        </div>
+                    </BrowserRouter>
+                </UserProvider>
+            </ConfigProvider>
         </I18nProvider>
     }
 }
\ No newline at end of file
@@ -0,0 +1,43 @@
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+use crate::contexts::alert_context::{describe_rule, use_alerts};
+
+#[function_component(AlertBanner)]
+pub fn alert_banner() -> Html
+{
+    let (i18n, _) = use_translation();
+    let alerts = use_alerts();
+
+    html!
+    {
+        <>
+            {
+                for alerts.firing.iter().map(|alert|
+                {
+                    let on_dismiss =
+                    {
+                        let on_dismiss = alerts.on_dismiss.clone();
+                        let rule_id = alert.rule.id;
+                        Callback::from(move |_| on_dismiss.emit(rule_id))
+                    };
+
+                    html!
+                    {
+                        <div class="alert-banner">
+                            <span>{ "⚠️" }</span>
+                            <p>
+                                <strong>{ describe_rule(&alert.rule, &i18n) }</strong>
+                                { " " }
+                                { i18n.t("project_dashboard.alert_current_value").replace("{value}", &format!("{:.1}", alert.value)) }
+                            </p>
+                            <button class="alert-banner-dismiss" onclick={on_dismiss} title={i18n.t("common.close")}>
+                                { "×" }
+                            </button>
+                        </div>
+                    }
+                })
+            }
+        </>
+    }
+}
\ No newline at end of file
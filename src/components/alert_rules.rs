@@ -0,0 +1,161 @@
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+use crate::contexts::alert_context::{describe_rule, use_alerts};
+use crate::models::alert::{AlertMetric, AlertRule};
+use crate::services::alert_service;
+
+#[function_component(AlertRules)]
+pub fn alert_rules() -> Html
+{
+    let (i18n, _) = use_translation();
+    let alerts = use_alerts();
+
+    let metric = use_state(|| AlertMetric::Cpu);
+    let threshold = use_state(|| "80".to_string());
+    let duration = use_state(|| "60".to_string());
+    let error = use_state(|| None::<String>);
+
+    let on_metric_change =
+    {
+        let metric = metric.clone();
+        Callback::from(move |e: Event|
+        {
+            let value = e.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
+            metric.set(if value == "memory" { AlertMetric::Memory } else { AlertMetric::Cpu });
+        })
+    };
+
+    let on_threshold_input =
+    {
+        let threshold = threshold.clone();
+        Callback::from(move |e: InputEvent|
+        {
+            threshold.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value());
+        })
+    };
+
+    let on_duration_input =
+    {
+        let duration = duration.clone();
+        Callback::from(move |e: InputEvent|
+        {
+            duration.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value());
+        })
+    };
+
+    let on_add =
+    {
+        let alerts = alerts.clone();
+        let metric = metric.clone();
+        let threshold = threshold.clone();
+        let duration = duration.clone();
+        let error = error.clone();
+        let i18n = i18n.clone();
+
+        Callback::from(move |e: SubmitEvent|
+        {
+            e.prevent_default();
+
+            let parsed_threshold = threshold.trim().parse::<f64>().ok().filter(|t| *t > 0.0);
+            let parsed_duration = duration.trim().parse::<u32>().ok();
+
+            let (Some(threshold), Some(duration_secs)) = (parsed_threshold, parsed_duration)
+            else
+            {
+                error.set(Some(i18n.t("project_dashboard.alert_invalid_rule")));
+                return;
+            };
+            error.set(None);
+
+            // Adding a rule is a user gesture, the only moment browsers allow asking
+            alert_service::request_notification_permission();
+
+            let mut rules = alerts.rules.clone();
+            let id = rules.iter().map(|r| r.id + 1).max().unwrap_or(1);
+            rules.push(AlertRule
+            {
+                id,
+                metric: *metric,
+                threshold,
+                duration_secs,
+            });
+            alerts.on_rules_change.emit(rules);
+        })
+    };
+
+    html!
+    {
+        <div class="card" style="margin-top: var(--spacing-lg);">
+            <h2>{ i18n.t("project_dashboard.card_title_alerts") }</h2>
+            <p style="color: var(--color-text-secondary); margin-bottom: var(--spacing-md);">
+                { i18n.t("project_dashboard.alerts_description") }
+            </p>
+
+            if alerts.rules.is_empty()
+            {
+                <p style="color: var(--color-text-secondary);">{ i18n.t("project_dashboard.alerts_empty") }</p>
+            }
+            else
+            {
+                <ul class="alert-rules-list">
+                    {
+                        for alerts.rules.iter().map(|rule|
+                        {
+                            let on_delete =
+                            {
+                                let alerts = alerts.clone();
+                                let rule_id = rule.id;
+                                Callback::from(move |_|
+                                {
+                                    let rules = alerts
+                                        .rules
+                                        .iter()
+                                        .filter(|r| r.id != rule_id)
+                                        .cloned()
+                                        .collect();
+                                    alerts.on_rules_change.emit(rules);
+                                })
+                            };
+
+                            html!
+                            {
+                                <li>
+                                    <span>{ describe_rule(rule, &i18n) }</span>
+                                    <button class="button-danger" onclick={on_delete}>
+                                        { i18n.t("project_dashboard.alert_delete_button") }
+                                    </button>
+                                </li>
+                            }
+                        })
+                    }
+                </ul>
+            }
+
+            <form class="alert-rule-form" onsubmit={on_add}>
+                <select class="text-input" onchange={on_metric_change}>
+                    <option value="cpu" selected={*metric == AlertMetric::Cpu}>
+                        { i18n.t("project_dashboard.alert_metric_cpu") }
+                    </option>
+                    <option value="memory" selected={*metric == AlertMetric::Memory}>
+                        { i18n.t("project_dashboard.alert_metric_memory") }
+                    </option>
+                </select>
+                <label>
+                    { i18n.t("project_dashboard.alert_threshold_label") }
+                    <input type="number" class="text-input" min="1" step="any" value={(*threshold).clone()} oninput={on_threshold_input} />
+                </label>
+                <label>
+                    { i18n.t("project_dashboard.alert_duration_label") }
+                    <input type="number" class="text-input" min="0" value={(*duration).clone()} oninput={on_duration_input} />
+                </label>
+                <button type="submit" class="button-primary">{ i18n.t("project_dashboard.alert_add_button") }</button>
+            </form>
+
+            if let Some(err) = &*error
+            {
+                <p class="error">{ err }</p>
+            }
+        </div>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,196 @@
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+use crate::components::deployment_history::format_date;
+use crate::components::deployment_progress::get_stage_translation_key;
+use crate::contexts::sse_context::use_sse_deployment;
+use crate::models::deployment::{WebhookDelivery, WebhookDeliveryStatus};
+use crate::pages::project_dashboard::translate_error;
+use crate::services::api_client::ApiError;
+use crate::services::project_service;
+use crate::services::sse_service::DeploymentStage;
+
+const SHORT_SHA_LEN: usize = 7;
+
+#[derive(Properties, PartialEq)]
+pub struct AutoDeploySettingsProps
+{
+    pub project_id: i32,
+    pub source_branch: Option<String>,
+    pub enabled: bool,
+    pub on_update: Callback<()>,
+}
+
+/// Redeploy-on-push toggle of a GitHub project, with the last push GitHub notified us about.
+/// Must be rendered inside an `SseProvider`.
+#[function_component(AutoDeploySettings)]
+pub fn auto_deploy_settings(props: &AutoDeploySettingsProps) -> Html
+{
+    let (i18n, _) = use_translation();
+    let deployment_stage = use_sse_deployment();
+    let is_saving = use_state(|| false);
+    // Follows the checkbox, reverted when saving fails
+    let enabled = use_state(|| props.enabled);
+    let toggle_error = use_state(|| None::<ApiError>);
+    let delivery_error = use_state(|| None::<ApiError>);
+    let last_delivery = use_state(|| None::<WebhookDelivery>);
+
+    {
+        let enabled = enabled.clone();
+        use_effect_with(props.enabled, move |&value|
+        {
+            enabled.set(value);
+            || ()
+        });
+    }
+
+    // A push-triggered deployment that just started or ended changes the last delivery
+    let deployment_boundary = matches!(
+        deployment_stage,
+        Some(DeploymentStage::Started | DeploymentStage::Completed { .. } | DeploymentStage::Failed { .. } | DeploymentStage::Cancelled)
+    );
+
+    {
+        let last_delivery = last_delivery.clone();
+        let delivery_error = delivery_error.clone();
+
+        use_effect_with((props.project_id, deployment_boundary), move |&(project_id, _)|
+        {
+            wasm_bindgen_futures::spawn_local(async move
+            {
+                match project_service::get_last_webhook_delivery(project_id).await
+                {
+                    Ok(delivery) =>
+                    {
+                        delivery_error.set(None);
+                        last_delivery.set(delivery);
+                    }
+                    Err(e) => delivery_error.set(Some(e)),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_toggle =
+    {
+        let project_id = props.project_id;
+        let is_saving = is_saving.clone();
+        let enabled = enabled.clone();
+        let toggle_error = toggle_error.clone();
+        let on_update = props.on_update.clone();
+
+        Callback::from(move |e: Event|
+        {
+            let checked = e.target_unchecked_into::<web_sys::HtmlInputElement>().checked();
+            let is_saving = is_saving.clone();
+            let enabled = enabled.clone();
+            let toggle_error = toggle_error.clone();
+            let on_update = on_update.clone();
+
+            is_saving.set(true);
+            enabled.set(checked);
+            toggle_error.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move
+            {
+                match project_service::set_auto_deploy(project_id, checked).await
+                {
+                    Ok(_) => on_update.emit(()),
+                    Err(e) =>
+                    {
+                        enabled.set(!checked);
+                        toggle_error.set(Some(e));
+                    }
+                }
+                is_saving.set(false);
+            });
+        })
+    };
+
+    let branch = props.source_branch.clone().unwrap_or_else(|| "main".to_string());
+
+    let render_delivery = |delivery: &WebhookDelivery|
+    {
+        let sha = delivery.commit_sha.get(..SHORT_SHA_LEN).unwrap_or(&delivery.commit_sha);
+
+        let (status_class, status_label) = match delivery.status
+        {
+            WebhookDeliveryStatus::Deployed => ("deployment-status-success", i18n.t("project_dashboard.webhook_status_deployed")),
+            WebhookDeliveryStatus::Ignored => ("deployment-status-cancelled", i18n.t("project_dashboard.webhook_status_ignored")),
+            WebhookDeliveryStatus::Rejected => ("deployment-status-failed", i18n.t("project_dashboard.webhook_status_rejected")),
+        };
+
+        let deployment_result = delivery.deployment.as_ref().map(|d| match &d.final_stage
+        {
+            Some(stage @ DeploymentStage::Completed { .. }) => ("deployment-status-success", i18n.t(get_stage_translation_key(stage))),
+            Some(stage @ (DeploymentStage::Failed { .. } | DeploymentStage::Cancelled)) => ("deployment-status-failed", i18n.t(get_stage_translation_key(stage))),
+            Some(stage) => ("deployment-status-active", i18n.t(get_stage_translation_key(stage))),
+            None => ("deployment-status-active", i18n.t("project_dashboard.deployment_running")),
+        });
+
+        html!
+        {
+            <div class="webhook-delivery">
+                <div class="webhook-delivery-header">
+                    <strong>{ i18n.t("project_dashboard.webhook_last_delivery") }</strong>
+                    <span class={classes!("deployment-status", status_class)}>{ status_label }</span>
+                </div>
+                <p>
+                    <code>{ sha }</code>
+                    { format!(" {} · {}", delivery.branch, format_date(&delivery.delivered_at)) }
+                    if let Some(pusher) = &delivery.pusher
+                    {
+                        { format!(" · {}", pusher) }
+                    }
+                </p>
+                if let Some(message) = &delivery.commit_message
+                {
+                    <p class="webhook-delivery-message">{ message.lines().next().unwrap_or_default() }</p>
+                }
+                if let Some((class, label)) = deployment_result
+                {
+                    <p>
+                        { i18n.t("project_dashboard.webhook_resulting_deployment") }
+                        { " " }
+                        <span class={classes!("deployment-status", class)}>{ label }</span>
+                    </p>
+                }
+            </div>
+        }
+    };
+
+    html!
+    {
+        <div class="auto-deploy-settings">
+            <label class="checkbox-label" for="auto_deploy" style="display: flex; align-items: center; gap: var(--spacing-sm);">
+                <input
+                    type="checkbox"
+                    id="auto_deploy"
+                    checked={*enabled}
+                    onchange={on_toggle}
+                    disabled={*is_saving}
+                />
+                { i18n.t("project_dashboard.auto_deploy_label").replace("{branch}", &branch) }
+            </label>
+
+            if let Some(e) = &*toggle_error
+            {
+                <p class="error">{ translate_error(e, &i18n) }</p>
+            }
+
+            if let Some(e) = &*delivery_error
+            {
+                <p class="error">{ translate_error(e, &i18n) }</p>
+            }
+            else if let Some(delivery) = &*last_delivery
+            {
+                { render_delivery(delivery) }
+            }
+            else if *enabled
+            {
+                <p style="color: var(--color-text-secondary);">{ i18n.t("project_dashboard.webhook_no_delivery") }</p>
+            }
+        </div>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+use crate::contexts::sse_context::BuildLogContext;
+use crate::utils::ansi::render_message;
+
+/// Below this distance from the bottom, the console keeps following new output.
+const FOLLOW_THRESHOLD_PX: i32 = 40;
+
+#[derive(Properties, PartialEq)]
+pub struct BuildConsoleProps
+{
+    pub build_log: BuildLogContext,
+    /// Opens the console so the cause of the failure is visible right away
+    #[prop_or_default]
+    pub failed: bool,
+}
+
+/// Collapsible console streaming the build output of the current deployment.
+#[function_component(BuildConsole)]
+pub fn build_console(props: &BuildConsoleProps) -> Html
+{
+    let (i18n, _) = use_translation();
+    let expanded = use_state(|| false);
+    let output_ref = use_node_ref();
+    let follow = use_mut_ref(|| true);
+
+    {
+        let expanded = expanded.clone();
+        use_effect_with(props.failed, move |&failed|
+        {
+            if failed
+            {
+                expanded.set(true);
+            }
+            || ()
+        });
+    }
+
+    // Sticks to the bottom as lines arrive, unless the user scrolled up to read
+    {
+        let output_ref = output_ref.clone();
+        let follow = follow.clone();
+        let line_count = props.build_log.lines.len() + props.build_log.dropped;
+
+        use_effect_with((line_count, *expanded), move |_|
+        {
+            if *follow.borrow()
+                && let Some(output) = output_ref.cast::<web_sys::Element>()
+            {
+                output.set_scroll_top(output.scroll_height());
+            }
+            || ()
+        });
+    }
+
+    let on_scroll =
+    {
+        let output_ref = output_ref.clone();
+        Callback::from(move |_: Event|
+        {
+            if let Some(output) = output_ref.cast::<web_sys::Element>()
+            {
+                *follow.borrow_mut() = output.scroll_height() - output.scroll_top() - output.client_height() < FOLLOW_THRESHOLD_PX;
+            }
+        })
+    };
+
+    let on_toggle =
+    {
+        let expanded = expanded.clone();
+        Callback::from(move |_|
+        {
+            expanded.set(!*expanded);
+        })
+    };
+
+    let log = &props.build_log;
+
+    html!
+    {
+        <div class={classes!("build-console", props.failed.then_some("build-console-failed"))}>
+            <button type="button" class="build-console-toggle" onclick={on_toggle}>
+                { if *expanded { "▾ " } else { "▸ " } }
+                { i18n.t("create_project.build_output_title") }
+                <span class="build-console-count">
+                    { i18n.t("create_project.build_output_lines").replace("{count}", &(log.lines.len() + log.dropped).to_string()) }
+                </span>
+            </button>
+
+            if *expanded
+            {
+                <div class="logs-container build-console-output" ref={output_ref} onscroll={on_scroll}>
+                    if log.dropped > 0
+                    {
+                        <div class="placeholder">
+                            { i18n.t("create_project.build_output_truncated").replace("{count}", &log.dropped.to_string()) }
+                        </div>
+                    }
+                    { for log.lines.iter().map(|line| html! { <div class="build-console-line">{ render_message(line, &[]) }</div> }) }
+                </div>
+            }
+        </div>
+    }
+}
\ No newline at end of file
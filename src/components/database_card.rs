@@ -1,11 +1,12 @@
 use i18nrs::yew::use_translation;
 use yew::prelude::*;
 
+use crate::contexts::config_context::use_config;
 use crate::models::database::DatabaseDetails;
 use crate::models::project::ProjectDetails;
 use crate::pages::project_dashboard::translate_error;
 use crate::services::database_service;
-use crate::services::project_service::ApiError;
+use crate::services::api_client::ApiError;
 
 #[derive(Properties, PartialEq)]
 pub struct DatabaseCardProps
@@ -63,6 +64,7 @@ struct DatabaseDisplayProps
 fn database_display(props: &DatabaseDisplayProps) -> Html
 {
     let (i18n, _) = use_translation();
+    let config = use_config();
     let db = &props.database;
 
     html! 
@@ -76,7 +78,7 @@ fn database_display(props: &DatabaseDisplayProps) -> Html
 
             <div style="margin-top: var(--spacing-md);">
                 <a
-                    href="https://phpmyadmin.hangar.garageisep.com"
+                    href={config.phpmyadmin_url.clone()}
                     target="_blank"
                     rel="noopener noreferrer"
                     class="button-primary"
@@ -0,0 +1,278 @@
+use i18nrs::I18n;
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+use crate::components::deployment_progress::get_stage_translation_key;
+use crate::contexts::sse_context::use_sse_deployment_event;
+use crate::models::deployment::{Deployment, DeploymentTrigger};
+use crate::pages::project_dashboard::translate_error;
+use crate::services::api_client::ApiError;
+use crate::services::project_service;
+use crate::services::sse_service::DeploymentStage;
+use crate::utils::log_parser::{format_timestamp, timestamp_ms};
+
+/// Length of the digest shown in the table, the full digest is in the tooltip
+const SHORT_DIGEST_LEN: usize = 12;
+
+fn trigger_label(trigger: DeploymentTrigger, i18n: &I18n) -> String
+{
+    match trigger
+    {
+        DeploymentTrigger::Create => i18n.t("project_dashboard.deployment_trigger_create"),
+        DeploymentTrigger::ImageUpdate => i18n.t("project_dashboard.deployment_trigger_image_update"),
+        DeploymentTrigger::Rebuild => i18n.t("project_dashboard.deployment_trigger_rebuild"),
+        DeploymentTrigger::EnvChange => i18n.t("project_dashboard.deployment_trigger_env_change"),
+        DeploymentTrigger::Rollback => i18n.t("project_dashboard.deployment_trigger_rollback"),
+        DeploymentTrigger::Push => i18n.t("project_dashboard.deployment_trigger_push"),
+        DeploymentTrigger::Unknown => i18n.t("project_dashboard.deployment_trigger_unknown"),
+    }
+}
+
+fn short_digest(digest: &str) -> &str
+{
+    let hash = digest.strip_prefix("sha256:").unwrap_or(digest);
+    hash.get(..SHORT_DIGEST_LEN).unwrap_or(hash)
+}
+
+pub fn format_date(timestamp: &str) -> String
+{
+    format_timestamp(timestamp).trim_end_matches('Z').replace('T', " ")
+}
+
+fn format_duration(started_at: &str, finished_at: &str) -> Option<String>
+{
+    let secs = ((timestamp_ms(finished_at)? - timestamp_ms(started_at)?) / 1000.0).max(0.0) as u64;
+    Some(if secs >= 60 { format!("{}m {:02}s", secs / 60, secs % 60) } else { format!("{}s", secs) })
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DeploymentHistoryProps
+{
+    pub project_id: i32,
+    pub project_name: String,
+    /// Whether the "roll back" action is offered
+    pub can_rollback: bool,
+    pub on_update: Callback<()>,
+    /// Bumped by the dashboard whenever the project may have been redeployed
+    pub reload: u32,
+}
+
+/// Past deployments of a project, most recent first.
+/// Must be rendered inside an `SseProvider`.
+#[function_component(DeploymentHistory)]
+pub fn deployment_history(props: &DeploymentHistoryProps) -> Html
+{
+    let (i18n, _) = use_translation();
+    let deployment_stage = use_sse_deployment_event().map(|e| e.stage);
+    let deployments = use_state(|| None::<Vec<Deployment>>);
+    let error = use_state(|| None::<ApiError>);
+    let rollback_error = use_state(|| None::<ApiError>);
+
+    // A deployment that just finished, successfully or not, has a new final stage to show
+    let finished = matches!(deployment_stage, Some(DeploymentStage::Completed { .. } | DeploymentStage::Failed { .. } | DeploymentStage::Cancelled));
+
+    {
+        let deployments = deployments.clone();
+        let error = error.clone();
+        let project_id = props.project_id;
+
+        use_effect_with((project_id, props.reload, finished), move |_|
+        {
+            wasm_bindgen_futures::spawn_local(async move
+            {
+                match project_service::get_project_deployments(project_id).await
+                {
+                    Ok(d) =>
+                    {
+                        error.set(None);
+                        deployments.set(Some(d));
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+            || ()
+        });
+    }
+
+    let is_deploying = matches!(&deployment_stage, Some(stage) if !matches!(stage, DeploymentStage::Completed { .. } | DeploymentStage::Failed { .. } | DeploymentStage::Cancelled));
+
+    // The live version cannot be rolled back to
+    let current_deployment_id = deployments
+        .as_ref()
+        .and_then(|d| d.iter().find(|d| matches!(d.final_stage, Some(DeploymentStage::Completed { .. }))))
+        .map(|d| d.id);
+
+    let render_deployment = |d: &Deployment|
+    {
+        let can_rollback_to = props.can_rollback
+            && d.image_digest.is_some()
+            && matches!(d.final_stage, Some(DeploymentStage::Completed { .. }))
+            && Some(d.id) != current_deployment_id;
+
+        let on_rollback =
+        {
+            let deployment_id = d.id;
+            let digest = d.image_digest.clone().unwrap_or_default();
+            let project_id = props.project_id;
+            let project_name = props.project_name.clone();
+            let rollback_error = rollback_error.clone();
+            let on_update = props.on_update.clone();
+            let i18n = i18n.clone();
+
+            Callback::from(move |_|
+            {
+                let confirm_message = i18n
+                    .t("project_dashboard.confirm_rollback")
+                    .replace("{name}", &project_name)
+                    .replace("{digest}", short_digest(&digest));
+
+                if web_sys::window()
+                    .unwrap()
+                    .confirm_with_message(&confirm_message)
+                    .unwrap()
+                {
+                    let rollback_error = rollback_error.clone();
+                    let on_update = on_update.clone();
+
+                    rollback_error.set(None);
+
+                    wasm_bindgen_futures::spawn_local(async move
+                    {
+                        match project_service::rollback_project(project_id, deployment_id).await
+                        {
+                            Ok(_) => on_update.emit(()),
+                            Err(e) => rollback_error.set(Some(e)),
+                        }
+                    });
+                }
+            })
+        };
+
+        let (status_class, status_label) = match &d.final_stage
+        {
+            Some(stage @ DeploymentStage::Completed { .. }) => ("deployment-status-success", i18n.t(get_stage_translation_key(stage))),
+            Some(stage @ DeploymentStage::Failed { .. }) => ("deployment-status-failed", i18n.t(get_stage_translation_key(stage))),
+            Some(stage @ DeploymentStage::Cancelled) => ("deployment-status-cancelled", i18n.t(get_stage_translation_key(stage))),
+            Some(stage) => ("deployment-status-active", i18n.t(get_stage_translation_key(stage))),
+            None => ("deployment-status-active", i18n.t("project_dashboard.deployment_running")),
+        };
+
+        let finished_at = d.finished_at.as_deref().map(format_date).unwrap_or_else(|| "-".to_string());
+        let duration = d
+            .finished_at
+            .as_deref()
+            .and_then(|finished_at| format_duration(&d.started_at, finished_at));
+
+        html!
+        {
+            <>
+                <tr>
+                    <td>{ trigger_label(d.trigger, &i18n) }</td>
+                    <td>{ &d.triggered_by }</td>
+                    <td>
+                        if let Some(digest) = &d.image_digest
+                        {
+                            <code title={digest.clone()}>{ short_digest(digest) }</code>
+                        }
+                        else
+                        {
+                            { "-" }
+                        }
+                    </td>
+                    <td>{ format_date(&d.started_at) }</td>
+                    <td>
+                        { finished_at }
+                        if let Some(duration) = duration
+                        {
+                            <span class="deployment-history-duration">{ format!(" ({})", duration) }</span>
+                        }
+                    </td>
+                    <td>
+                        <span class={classes!("deployment-status", status_class)}>{ status_label }</span>
+                    </td>
+                    if props.can_rollback
+                    {
+                        <td>
+                            if can_rollback_to
+                            {
+                                <button
+                                    class="button-primary deployment-rollback-button"
+                                    onclick={on_rollback}
+                                    disabled={is_deploying}
+                                >
+                                    { i18n.t("project_dashboard.rollback_button") }
+                                </button>
+                            }
+                        </td>
+                    }
+                </tr>
+                if let Some(DeploymentStage::Failed { error, stage }) = &d.final_stage
+                {
+                    <tr class="deployment-history-failure">
+                        <td colspan={if props.can_rollback { "7" } else { "6" }}>
+                            <strong>
+                                { i18n.t("project_dashboard.deployment_failed_at").replace("{stage}", stage) }
+                            </strong>
+                            <pre>{ error }</pre>
+                        </td>
+                    </tr>
+                }
+            </>
+        }
+    };
+
+    html!
+    {
+        <div class="card" style="margin-top: var(--spacing-lg);">
+            <h2>{ i18n.t("project_dashboard.card_title_deployments") }</h2>
+
+            if let Some(e) = &*error
+            {
+                <p class="error">{ translate_error(e, &i18n) }</p>
+            }
+
+            if let Some(e) = &*rollback_error
+            {
+                <p class="error">{ translate_error(e, &i18n) }</p>
+            }
+
+            if let Some(deployments) = &*deployments
+            {
+                if deployments.is_empty()
+                {
+                    <p style="color: var(--color-text-secondary);">
+                        { i18n.t("project_dashboard.deployments_empty") }
+                    </p>
+                }
+                else
+                {
+                    <div class="deployment-history">
+                        <table class="data-table">
+                            <thead>
+                                <tr>
+                                    <th>{ i18n.t("project_dashboard.deployment_trigger") }</th>
+                                    <th>{ i18n.t("project_dashboard.deployment_triggered_by") }</th>
+                                    <th>{ i18n.t("project_dashboard.deployment_digest") }</th>
+                                    <th>{ i18n.t("project_dashboard.deployment_started_at") }</th>
+                                    <th>{ i18n.t("project_dashboard.deployment_finished_at") }</th>
+                                    <th>{ i18n.t("project_dashboard.deployment_result") }</th>
+                                    if props.can_rollback
+                                    {
+                                        <th></th>
+                                    }
+                                </tr>
+                            </thead>
+                            <tbody>
+                                { for deployments.iter().map(render_deployment) }
+                            </tbody>
+                        </table>
+                    </div>
+                }
+            }
+            else if error.is_none()
+            {
+                <p>{ i18n.t("common.loading") }</p>
+            }
+        </div>
+    }
+}
\ No newline at end of file
@@ -1,11 +1,21 @@
+use gloo_timers::callback::Interval;
 use yew::prelude::*;
 use i18nrs::I18n;
+use crate::contexts::sse_context::{DeploymentTimelineContext, TimelineEntry};
+use crate::pages::project_dashboard::translate_error;
+use crate::services::api_client::ApiError;
+use crate::services::project_service;
 use crate::services::sse_service::DeploymentStage;
 
+const TIMER_TICK_MS: u32 = 1000;
+
 #[derive(Properties, PartialEq)]
 pub struct DeploymentProgressProps
 {
-    pub stage: DeploymentStage,
+    /// Project being deployed, used by the cancel button
+    pub project_id: i32,
+    /// Stages recorded as their events arrived, the last one is the current stage
+    pub timeline: DeploymentTimelineContext,
     #[prop_or_default]
     pub context: ProgressContext,
 }
@@ -18,22 +28,98 @@ pub enum ProgressContext
     Update,
 }
 
+fn format_duration(ms: f64) -> String
+{
+    let secs = ms.max(0.0) / 1000.0;
+    if secs >= 60.0
+    {
+        format!("{}m {:02}s", (secs / 60.0) as u64, (secs % 60.0) as u64)
+    }
+    else
+    {
+        format!("{:.1}s", secs)
+    }
+}
+
 #[function_component(DeploymentProgress)]
 pub fn deployment_progress(props: &DeploymentProgressProps) -> Html
 {
     let i18n = use_context::<I18n>().expect("I18n context not found");
+    let now_ms = use_state(js_sys::Date::now);
     
-    let is_failed = matches!(&props.stage, DeploymentStage::Failed { .. });
-    let is_completed = matches!(&props.stage, DeploymentStage::Completed { .. });
+    let is_cancelling = use_state(|| false);
+    let cancel_error = use_state(|| None::<ApiError>);
     
-    let progress_percent = calculate_progress(&props.stage);
-    let stage_key = get_stage_translation_key(&props.stage);
+    let stage = props.timeline.stage().cloned();
+    let is_failed = matches!(&stage, Some(DeploymentStage::Failed { .. }));
+    let is_completed = matches!(&stage, Some(DeploymentStage::Completed { .. }));
+    let is_cancelled = stage == Some(DeploymentStage::Cancelled);
+    let is_running = stage.is_some() && !is_failed && !is_completed && !is_cancelled;
+
+    // Ticks the running timer of the current stage
+    {
+        let now_ms = now_ms.clone();
+        use_effect_with(is_running, move |&is_running|
+        {
+            let interval = is_running.then(|| Interval::new(TIMER_TICK_MS, move || now_ms.set(js_sys::Date::now())));
+            move || drop(interval)
+        });
+    }
+
+    let Some(stage) = stage
+    else
+    {
+        return html! {};
+    };
+
+    let entries = &props.timeline.entries;
+    let total_ms = match (entries.first(), entries.last())
+    {
+        (Some(first), Some(last)) if !is_running => Some(last.at_ms - first.at_ms),
+        (Some(first), Some(last)) => Some(last.at_ms - first.at_ms + (*now_ms - last.received_ms)),
+        _ => None,
+    };
+
+    let render_entry = |(i, entry): (usize, &TimelineEntry)|
+    {
+        let next = entries.get(i + 1);
+        let is_last = next.is_none();
+        let is_terminal = matches!(entry.stage, DeploymentStage::Completed { .. } | DeploymentStage::Failed { .. } | DeploymentStage::Cancelled);
+
+        let (entry_class, duration) = match next
+        {
+            Some(next) => ("timeline-done", Some(format_duration(next.at_ms - entry.at_ms))),
+            None if is_terminal => (if is_failed || is_cancelled { "timeline-failed" } else { "timeline-done" }, None),
+            None => ("timeline-active", Some(format_duration(*now_ms - entry.received_ms))),
+        };
+
+        html!
+        {
+            <li class={classes!("deployment-timeline-entry", entry_class)}>
+                <span class="deployment-timeline-dot"></span>
+                <span class="deployment-timeline-label">{ i18n.t(get_stage_translation_key(&entry.stage)) }</span>
+                if let Some(duration) = duration
+                {
+                    <span class={classes!("deployment-timeline-duration", (is_last && is_running).then_some("timeline-running"))}>
+                        { duration }
+                    </span>
+                }
+            </li>
+        }
+    };
+    
+    let progress_percent = calculate_progress(&stage);
+    let stage_key = get_stage_translation_key(&stage);
     let current_stage_label = i18n.t(stage_key);
     
     let status_class = if is_failed 
     {
         "deployment-status-failed"
     } 
+    else if is_cancelled
+    {
+        "deployment-status-cancelled"
+    } 
     else if is_completed 
     {
         "deployment-status-success"
@@ -43,6 +129,41 @@ pub fn deployment_progress(props: &DeploymentProgressProps) -> Html
         "deployment-status-active"
     };
 
+    let on_cancel =
+    {
+        let project_id = props.project_id;
+        let is_cancelling = is_cancelling.clone();
+        let cancel_error = cancel_error.clone();
+        let i18n = i18n.clone();
+
+        Callback::from(move |_|
+        {
+            if !web_sys::window()
+                .unwrap()
+                .confirm_with_message(&i18n.t("create_project.confirm_cancel_deployment"))
+                .unwrap()
+            {
+                return;
+            }
+
+            let is_cancelling = is_cancelling.clone();
+            let cancel_error = cancel_error.clone();
+
+            is_cancelling.set(true);
+            cancel_error.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move
+            {
+                // On success the button goes away with the `Cancelled` event
+                if let Err(e) = project_service::cancel_deployment(project_id).await
+                {
+                    cancel_error.set(Some(e));
+                }
+                is_cancelling.set(false);
+            });
+        })
+    };
+
     let title_key = match props.context
     {
         ProgressContext::Creation => "create_project.deployment_in_progress",
@@ -79,6 +200,42 @@ pub fn deployment_progress(props: &DeploymentProgressProps) -> Html
                     </div>
                 </div>
 
+                if !entries.is_empty()
+                {
+                    <ol class="deployment-timeline">
+                        { for entries.iter().enumerate().map(render_entry) }
+                    </ol>
+                    if let Some(total_ms) = total_ms
+                    {
+                        <div class="deployment-timeline-total">
+                            { i18n.t("create_project.deployment_total_time").replace("{duration}", &format_duration(total_ms)) }
+                        </div>
+                    }
+                }
+
+                if is_running
+                {
+                    <div class="deployment-cancel">
+                        <button type="button" class="button-danger" onclick={on_cancel} disabled={*is_cancelling}>
+                            {
+                                if *is_cancelling
+                                {
+                                    i18n.t("create_project.cancel_deployment_button_loading")
+                                }
+                                else
+                                {
+                                    i18n.t("create_project.cancel_deployment_button")
+                                }
+                            }
+                        </button>
+                    </div>
+                }
+
+                if let Some(e) = &*cancel_error
+                {
+                    <p class="error">{ translate_error(e, &i18n) }</p>
+                }
+
                 {
                     if is_completed 
                     {
@@ -89,9 +246,18 @@ pub fn deployment_progress(props: &DeploymentProgressProps) -> Html
                             </div>
                         }
                     }
+                    else if is_cancelled
+                    {
+                        html! 
+                        {
+                            <div class="deployment-error-message">
+                                { i18n.t("create_project.deployment_cancelled_message") }
+                            </div>
+                        }
+                    }
                     else if is_failed 
                     {
-                        if let DeploymentStage::Failed { error, .. } = &props.stage 
+                        if let DeploymentStage::Failed { error, .. } = &stage 
                         {
                             html! 
                             {
@@ -115,7 +281,7 @@ pub fn deployment_progress(props: &DeploymentProgressProps) -> Html
     }
 }
 
-fn get_stage_translation_key(stage: &DeploymentStage) -> &'static str
+pub fn get_stage_translation_key(stage: &DeploymentStage) -> &'static str
 {
     match stage
     {
@@ -141,6 +307,7 @@ fn get_stage_translation_key(stage: &DeploymentStage) -> &'static str
         DeploymentStage::CleaningUp => "create_project.deployment_stage_cleanup",
         DeploymentStage::Completed { .. } => "create_project.deployment_stage_completed",
         DeploymentStage::Failed { .. } => "create_project.deployment_stage_failed",
+        DeploymentStage::Cancelled => "create_project.deployment_stage_cancelled",
     }
 }
 
@@ -170,5 +337,6 @@ fn calculate_progress(stage: &DeploymentStage) -> u8
         DeploymentStage::CleaningUp => 98,
         DeploymentStage::Completed { .. } => 100,
         DeploymentStage::Failed { .. } => 100,
+        DeploymentStage::Cancelled => 100,
     }
 }
\ No newline at end of file
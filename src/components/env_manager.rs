@@ -6,7 +6,8 @@ use i18nrs::yew::use_translation;
 use yew::prelude::*;
 
 use crate::models::project::UpdateEnvPayload;
-use crate::services::project_service::{self, ApiError};
+use crate::services::api_client::ApiError;
+use crate::services::project_service;
 
 use crate::pages::project_dashboard::translate_error;
 
@@ -0,0 +1,36 @@
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+use crate::utils::export::ExportFormat;
+
+#[derive(Properties, PartialEq)]
+pub struct ExportButtonsProps
+{
+    pub on_export: Callback<ExportFormat>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+#[function_component(ExportButtons)]
+pub fn export_buttons(props: &ExportButtonsProps) -> Html
+{
+    let (i18n, _) = use_translation();
+
+    let on_click = |format: ExportFormat|
+    {
+        let on_export = props.on_export.clone();
+        Callback::from(move |_| on_export.emit(format))
+    };
+
+    html!
+    {
+        <div style="display: flex; gap: var(--spacing-sm);">
+            <button class="metrics-range-button" onclick={on_click(ExportFormat::Csv)} disabled={props.disabled}>
+                { i18n.t("common.export_csv") }
+            </button>
+            <button class="metrics-range-button" onclick={on_click(ExportFormat::Json)} disabled={props.disabled}>
+                { i18n.t("common.export_json") }
+            </button>
+        </div>
+    }
+}
\ No newline at end of file
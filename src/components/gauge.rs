@@ -14,7 +14,7 @@ pub fn gauge(props: &GaugeProps) -> Html
 {
     let percentage = if props.max_value > 0.0 
     {
-        (props.value / props.max_value).clamp(0.0, 1.0)
+        (props.value / props.max_value * 100.0).clamp(0.0, 100.0)
     } 
     else 
     {
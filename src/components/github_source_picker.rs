@@ -0,0 +1,279 @@
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+use crate::components::searchable_select::SearchableSelect;
+use crate::models::github::GithubRepository;
+use crate::pages::project_dashboard::translate_error;
+use crate::services::api_client::ApiError;
+use crate::services::github_service;
+
+#[derive(Properties, PartialEq)]
+pub struct GithubSourcePickerProps
+{
+    pub repo_url: String,
+    pub branch: String,
+    pub root_dir: String,
+    pub on_repo_url_change: Callback<String>,
+    pub on_branch_change: Callback<String>,
+    pub on_root_dir_change: Callback<String>,
+    /// Whether the current selection exists on GitHub, as far as the lists loaded so far tell
+    pub on_validity_change: Callback<bool>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// Repository, branch and root directory dropdowns fed by the GitHub App installation.
+/// Falls back to free-text inputs when the repositories cannot be listed.
+#[function_component(GithubSourcePicker)]
+pub fn github_source_picker(props: &GithubSourcePickerProps) -> Html
+{
+    let (i18n, _) = use_translation();
+    let repositories = use_state(|| None::<Result<Vec<GithubRepository>, ApiError>>);
+    let branches = use_state(|| None::<Vec<String>>);
+    let directories = use_state(|| None::<Vec<String>>);
+    // Latest lists asked for: answers to earlier requests arriving late are dropped
+    let branches_request = use_mut_ref(|| None::<String>);
+    let directories_request = use_mut_ref(|| None::<(String, String)>);
+
+    {
+        let repositories = repositories.clone();
+        use_effect_with((), move |_|
+        {
+            wasm_bindgen_futures::spawn_local(async move
+            {
+                repositories.set(Some(github_service::list_repositories().await));
+            });
+            || ()
+        });
+    }
+
+    let loaded_repositories = match &*repositories
+    {
+        Some(Ok(r)) => Some(r),
+        _ => None,
+    };
+
+    let selected = loaded_repositories.and_then(|r| r.iter().find(|r| r.html_url == props.repo_url));
+    let selected_name = selected.map(|r| r.full_name.clone());
+
+    {
+        let branches = branches.clone();
+        let branches_request = branches_request.clone();
+        use_effect_with(selected_name.clone(), move |full_name|
+        {
+            branches.set(None);
+            *branches_request.borrow_mut() = full_name.clone();
+            if let Some(full_name) = full_name.clone()
+            {
+                wasm_bindgen_futures::spawn_local(async move
+                {
+                    let result = github_service::list_branches(&full_name).await;
+                    if branches_request.borrow().as_ref() != Some(&full_name)
+                    {
+                        return;
+                    }
+                    match result
+                    {
+                        Ok(b) => branches.set(Some(b)),
+                        Err(e) => gloo_console::warn!("Branches unavailable:", e.error_code),
+                    }
+                });
+            }
+            || ()
+        });
+    }
+
+    {
+        let directories = directories.clone();
+        let directories_request = directories_request.clone();
+        let branch = selected.map(|r| if props.branch.trim().is_empty() { r.default_branch.clone() } else { props.branch.trim().to_string() });
+
+        use_effect_with((selected_name.clone(), branch), move |(full_name, branch)|
+        {
+            directories.set(None);
+            let request = full_name.clone().zip(branch.clone());
+            *directories_request.borrow_mut() = request.clone();
+            if let Some((full_name, branch)) = request
+            {
+                wasm_bindgen_futures::spawn_local(async move
+                {
+                    let result = github_service::list_directories(&full_name, &branch).await;
+                    if directories_request.borrow().as_ref() != Some(&(full_name, branch))
+                    {
+                        return;
+                    }
+                    match result
+                    {
+                        Ok(d) => directories.set(Some(d)),
+                        Err(e) => gloo_console::warn!("Directories unavailable:", e.error_code),
+                    }
+                });
+            }
+            || ()
+        });
+    }
+
+    let repo_valid = loaded_repositories.is_none() || selected.is_some();
+    let branch_valid = props.branch.trim().is_empty()
+        || branches.as_ref().is_none_or(|b| b.iter().any(|b| b == props.branch.trim()));
+    // Only the first segment can be checked against the top-level listing
+    let root_dir = props.root_dir.trim().trim_matches('/');
+    let root_dir_valid = root_dir.is_empty()
+        || directories.as_ref().is_none_or(|d| d.iter().any(|d| Some(d.as_str()) == root_dir.split('/').next()));
+
+    {
+        let on_validity_change = props.on_validity_change.clone();
+        use_effect_with(repo_valid && branch_valid && root_dir_valid, move |&valid|
+        {
+            on_validity_change.emit(valid);
+            || ()
+        });
+    }
+
+    let on_repo_select =
+    {
+        let repositories = loaded_repositories.cloned().unwrap_or_default();
+        let on_repo_url_change = props.on_repo_url_change.clone();
+        let on_branch_change = props.on_branch_change.clone();
+        let on_root_dir_change = props.on_root_dir_change.clone();
+
+        Callback::from(move |full_name: String|
+        {
+            if let Some(repo) = repositories.iter().find(|r| r.full_name == full_name)
+            {
+                on_repo_url_change.emit(repo.html_url.clone());
+                on_branch_change.emit(repo.default_branch.clone());
+                on_root_dir_change.emit(String::new());
+            }
+        })
+    };
+
+    let on_root_dir_select =
+    {
+        let on_root_dir_change = props.on_root_dir_change.clone();
+        Callback::from(move |dir: String|
+        {
+            on_root_dir_change.emit(if dir == "/" { String::new() } else { dir });
+        })
+    };
+
+    let text_input = |id: &'static str, value: &String, placeholder: String, on_change: &Callback<String>, required: bool|
+    {
+        let on_change = on_change.clone();
+        let onchange = Callback::from(move |e: Event|
+        {
+            on_change.emit(e.target_unchecked_into::<web_sys::HtmlInputElement>().value());
+        });
+
+        html!
+        {
+            <input type="text" id={id} class="text-input"
+                placeholder={placeholder}
+                value={value.clone()}
+                onchange={onchange}
+                required={required}
+                disabled={props.disabled} />
+        }
+    };
+
+    let field_error = |valid: bool, key: &str|
+    {
+        if valid
+        {
+            html! {}
+        }
+        else
+        {
+            html! { <small class="field-error">{ i18n.t(key) }</small> }
+        }
+    };
+
+    let repository_field = match &*repositories
+    {
+        None => html! { <p style="color: var(--color-text-secondary)">{ i18n.t("common.loading") }</p> },
+        Some(Ok(repositories)) => html!
+        {
+            <>
+                <SearchableSelect
+                    id="github_repo_url"
+                    options={repositories.iter().map(|r| r.full_name.clone()).collect::<Vec<_>>()}
+                    value={selected_name.clone().unwrap_or_else(|| props.repo_url.clone())}
+                    on_select={on_repo_select}
+                    placeholder={i18n.t("create_project.github_repo_search_placeholder")}
+                    disabled={props.disabled}
+                    invalid={!repo_valid}
+                />
+                { field_error(repo_valid || props.repo_url.is_empty(), "create_project.github_repo_not_found") }
+            </>
+        },
+        Some(Err(e)) => html!
+        {
+            <>
+                { text_input("github_repo_url", &props.repo_url, i18n.t("create_project.github_repo_url_placeholder"), &props.on_repo_url_change, true) }
+                <small style="color: var(--color-text-secondary)">
+                    { i18n.t("create_project.github_repos_unavailable").replace("{error}", &translate_error(e, &i18n)) }
+                </small>
+            </>
+        },
+    };
+
+    let branch_field = match &*branches
+    {
+        Some(branches) => html!
+        {
+            <>
+                <SearchableSelect
+                    id="github_branch"
+                    options={branches.clone()}
+                    value={props.branch.clone()}
+                    on_select={props.on_branch_change.clone()}
+                    placeholder="main"
+                    disabled={props.disabled}
+                    invalid={!branch_valid}
+                />
+                { field_error(branch_valid, "create_project.github_branch_not_found") }
+            </>
+        },
+        None => text_input("github_branch", &props.branch, "main".to_string(), &props.on_branch_change, false),
+    };
+
+    let root_dir_field = match &*directories
+    {
+        Some(directories) => html!
+        {
+            <>
+                <SearchableSelect
+                    id="github_root_dir"
+                    options={std::iter::once("/".to_string()).chain(directories.iter().map(|d| format!("/{}", d))).collect::<Vec<_>>()}
+                    value={if props.root_dir.is_empty() { "/".to_string() } else { props.root_dir.clone() }}
+                    on_select={on_root_dir_select}
+                    placeholder="/"
+                    disabled={props.disabled}
+                    invalid={!root_dir_valid}
+                />
+                { field_error(root_dir_valid, "create_project.github_root_dir_not_found") }
+            </>
+        },
+        None => text_input("github_root_dir", &props.root_dir, "/".to_string(), &props.on_root_dir_change, false),
+    };
+
+    html!
+    {
+        <>
+            <div class="form-group">
+                <label for="github_repo_url">{ i18n.t("create_project.github_repo_url_label") }</label>
+                { repository_field }
+            </div>
+            <div class="form-group">
+                <label for="github_branch">{ i18n.t("create_project.github_branch_label") }</label>
+                { branch_field }
+                <small style="color: var(--color-text-secondary)">{ i18n.t("create_project.github_branch_help") }</small>
+            </div>
+            <div class="form-group">
+                <label for="github_root_dir">{ i18n.t("create_project.github_root_dir_label") }</label>
+                { root_dir_field }
+                <small style="color: var(--color-text-secondary)">{ i18n.t("create_project.github_root_dir_help") }</small>
+            </div>
+        </>
+    }
+}
\ No newline at end of file
@@ -3,11 +3,16 @@
 use i18nrs::yew::use_translation;
 use yew::prelude::*;
 
+use crate::components::auto_deploy_settings::AutoDeploySettings;
+use crate::components::build_console::BuildConsole;
 use crate::components::deployment_progress::{DeploymentProgress, ProgressContext};
-use crate::contexts::sse_context::use_sse_deployment;
+use crate::components::scan_report_table::ScanReportTable;
+use crate::contexts::sse_context::{use_sse_build_log, use_sse_deployment_event, use_sse_deployment_timeline};
 use crate::models::project::ProjectSourceType;
-use crate::services::project_service::{self, ApiError};
+use crate::services::api_client::ApiError;
+use crate::services::project_service;
 use crate::services::sse_service::DeploymentStage;
+use crate::utils::scan_report::parse_scan_details;
 
 use crate::pages::project_dashboard::translate_error;
 
@@ -19,6 +24,10 @@ pub struct ImageUpdateFormProps
     pub project_id: i32,
     pub project_name: String,
     pub source_type: ProjectSourceType,
+    #[prop_or_default]
+    pub source_branch: Option<String>,
+    #[prop_or_default]
+    pub auto_deploy: bool,
     pub on_update: Callback<()>,
 }
 
@@ -26,7 +35,10 @@ pub struct ImageUpdateFormProps
 pub fn image_update_form(props: &ImageUpdateFormProps) -> Html
 {
     let (i18n, _) = use_translation();
-    let deployment_stage = use_sse_deployment();
+    let deployment_event = use_sse_deployment_event();
+    let deployment_stage = deployment_event.as_ref().map(|e| e.stage.clone());
+    let build_log = use_sse_build_log();
+    let deployment_timeline = use_sse_deployment_timeline();
     
     let new_image_url = use_state(String::new);
     let is_updating = use_state(|| false);
@@ -78,7 +90,7 @@ pub fn image_update_form(props: &ImageUpdateFormProps) -> Html
                     })
                     .forget();
                 }
-                Some(DeploymentStage::Failed { .. }) =>
+                Some(DeploymentStage::Failed { .. }) | Some(DeploymentStage::Cancelled) =>
                 {
                     is_updating.set(false);
                 }
@@ -89,7 +101,7 @@ pub fn image_update_form(props: &ImageUpdateFormProps) -> Html
     }
 
     let is_deploying = deployment_stage.is_some()
-        && !matches!(&deployment_stage, Some(DeploymentStage::Failed { .. }));
+        && !matches!(&deployment_stage, Some(DeploymentStage::Failed { .. }) | Some(DeploymentStage::Cancelled));
 
     let on_input_change =
     {
@@ -212,7 +224,7 @@ pub fn image_update_form(props: &ImageUpdateFormProps) -> Html
             </p>
 
             {
-                if let Some(stage) = &deployment_stage
+                if let Some(event) = &deployment_event
                 {
                     if !*hide_progress
                     {
@@ -227,7 +239,8 @@ pub fn image_update_form(props: &ImageUpdateFormProps) -> Html
                                     { "×" }
                                 </button>
                                 <DeploymentProgress
-                                    stage={stage.clone()}
+                                    project_id={event.project_id}
+                                    timeline={deployment_timeline.clone()}
                                     context={ProgressContext::Update}
                                 />
                             </div>
@@ -244,6 +257,24 @@ pub fn image_update_form(props: &ImageUpdateFormProps) -> Html
                 }
             }
 
+            if is_github
+            {
+                <AutoDeploySettings
+                    project_id={props.project_id}
+                    source_branch={props.source_branch.clone()}
+                    enabled={props.auto_deploy}
+                    on_update={props.on_update.clone()}
+                />
+            }
+
+            if !build_log.lines.is_empty()
+            {
+                <BuildConsole
+                    build_log={build_log.clone()}
+                    failed={matches!(deployment_stage, Some(DeploymentStage::Failed { .. } | DeploymentStage::Cancelled))}
+                />
+            }
+
             <form onsubmit={on_submit}>
                 if !is_github
                 {
@@ -267,6 +298,12 @@ pub fn image_update_form(props: &ImageUpdateFormProps) -> Html
                 if let Some(err) = &*update_error
                 {
                     <p class="error">{ translate_error(err, &i18n) }</p>
+
+                    if err.error_code == "IMAGE_SCAN_FAILED"
+                        && let Some(vulnerabilities) = err.details.as_deref().and_then(parse_scan_details)
+                    {
+                        <ScanReportTable vulnerabilities={vulnerabilities} />
+                    }
                 }
 
                 <button type="submit" class="button-primary" disabled={*is_updating}>
@@ -0,0 +1,151 @@
+use yew::prelude::*;
+
+const WIDTH: f64 = 600.0;
+const HEIGHT: f64 = 160.0;
+const PADDING_LEFT: f64 = 48.0;
+const PADDING_RIGHT: f64 = 8.0;
+const PADDING_TOP: f64 = 8.0;
+const PADDING_BOTTOM: f64 = 20.0;
+const TOOLTIP_WIDTH: f64 = 120.0;
+
+#[derive(Properties, PartialEq)]
+pub struct MetricsChartProps
+{
+    pub label: String,
+    /// `(milliseconds since the epoch, value)`, oldest first.
+    pub points: Vec<(f64, f64)>,
+    /// Right edge of the chart, in milliseconds since the epoch.
+    pub now_ms: f64,
+    pub window_ms: f64,
+    pub y_max: f64,
+    pub unit: String,
+}
+
+fn format_clock(ms: f64) -> String
+{
+    let date = js_sys::Date::new(&ms.into());
+    format!("{:02}:{:02}:{:02}", date.get_hours(), date.get_minutes(), date.get_seconds())
+}
+
+/// SVG line chart of a metric over a sliding time window, with a tooltip on hover.
+#[function_component(MetricsChart)]
+pub fn metrics_chart(props: &MetricsChartProps) -> Html
+{
+    let svg_ref = use_node_ref();
+    let hovered = use_state(|| None::<usize>);
+
+    let start_ms = props.now_ms - props.window_ms;
+    let plot_width = WIDTH - PADDING_LEFT - PADDING_RIGHT;
+    let plot_height = HEIGHT - PADDING_TOP - PADDING_BOTTOM;
+    let y_max = if props.y_max > 0.0 { props.y_max } else { 1.0 };
+
+    let x_of = |ms: f64| PADDING_LEFT + (ms - start_ms) / props.window_ms * plot_width;
+    let y_of = |value: f64| PADDING_TOP + plot_height - (value / y_max).clamp(0.0, 1.0) * plot_height;
+
+    let path = props
+        .points
+        .iter()
+        .enumerate()
+        .map(|(i, &(ms, value))| format!("{}{:.1} {:.1}", if i == 0 { "M" } else { " L" }, x_of(ms), y_of(value)))
+        .collect::<String>();
+
+    let on_mouse_move =
+    {
+        let svg_ref = svg_ref.clone();
+        let hovered = hovered.clone();
+        let points = props.points.clone();
+        let window_ms = props.window_ms;
+
+        Callback::from(move |e: MouseEvent|
+        {
+            let Some(svg) = svg_ref.cast::<web_sys::Element>()
+            else
+            {
+                return;
+            };
+
+            let rect = svg.get_bounding_client_rect();
+            if rect.width() <= 0.0
+            {
+                return;
+            }
+
+            // The SVG is scaled to its box, so convert from screen pixels to viewBox units first
+            let x = (e.client_x() as f64 - rect.left()) / rect.width() * WIDTH;
+            let ms = start_ms + (x - PADDING_LEFT) / plot_width * window_ms;
+
+            let nearest = points
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| (a.0 - ms).abs().total_cmp(&(b.0 - ms).abs()))
+                .map(|(i, _)| i);
+
+            hovered.set(nearest);
+        })
+    };
+
+    let on_mouse_leave =
+    {
+        let hovered = hovered.clone();
+        Callback::from(move |_: MouseEvent| hovered.set(None))
+    };
+
+    let tooltip = (*hovered).and_then(|i| props.points.get(i)).map(|&(ms, value)|
+    {
+        let x = x_of(ms);
+        let y = y_of(value);
+        let box_x = if x + TOOLTIP_WIDTH + 8.0 > WIDTH { x - TOOLTIP_WIDTH - 8.0 } else { x + 8.0 };
+
+        html!
+        {
+            <g class="metrics-chart-tooltip">
+                <line x1={x.to_string()} x2={x.to_string()} y1={PADDING_TOP.to_string()} y2={(PADDING_TOP + plot_height).to_string()} />
+                <circle cx={x.to_string()} cy={y.to_string()} r="3" />
+                <rect x={box_x.to_string()} y={PADDING_TOP.to_string()} width={TOOLTIP_WIDTH.to_string()} height="36" rx="4" />
+                <text x={(box_x + 8.0).to_string()} y={(PADDING_TOP + 15.0).to_string()}>
+                    { format_clock(ms) }
+                </text>
+                <text x={(box_x + 8.0).to_string()} y={(PADDING_TOP + 30.0).to_string()} class="metrics-chart-tooltip-value">
+                    { format!("{:.1} {}", value, props.unit) }
+                </text>
+            </g>
+        }
+    });
+
+    html!
+    {
+        <div class="metrics-chart">
+            <h3>{ &props.label }</h3>
+            <svg
+                ref={svg_ref}
+                viewBox={format!("0 0 {} {}", WIDTH, HEIGHT)}
+                onmousemove={on_mouse_move}
+                onmouseleave={on_mouse_leave}
+            >
+                {
+                    for [0.0, 0.5, 1.0].into_iter().map(|fraction|
+                    {
+                        let y = y_of(y_max * fraction);
+                        html!
+                        {
+                            <>
+                                <line class="metrics-chart-grid" x1={PADDING_LEFT.to_string()} x2={(WIDTH - PADDING_RIGHT).to_string()} y1={y.to_string()} y2={y.to_string()} />
+                                <text class="metrics-chart-axis" x={(PADDING_LEFT - 4.0).to_string()} y={(y + 4.0).to_string()} text-anchor="end">
+                                    { format!("{:.0}", y_max * fraction) }
+                                </text>
+                            </>
+                        }
+                    })
+                }
+                <text class="metrics-chart-axis" x={PADDING_LEFT.to_string()} y={(HEIGHT - 4.0).to_string()}>
+                    { format_clock(start_ms) }
+                </text>
+                <text class="metrics-chart-axis" x={(WIDTH - PADDING_RIGHT).to_string()} y={(HEIGHT - 4.0).to_string()} text-anchor="end">
+                    { format_clock(props.now_ms) }
+                </text>
+                <path class="metrics-chart-line" d={path} />
+                { for tooltip }
+            </svg>
+        </div>
+    }
+}
\ No newline at end of file
@@ -13,4 +13,18 @@ pub mod project_controls;
 pub mod project_info;
 pub mod project_logs;
 pub mod project_metrics;
-pub mod system_notifications;
\ No newline at end of file
+pub mod system_notifications;
+pub mod sse_connection_badge;
+pub mod virtual_list;
+pub mod metrics_chart;
+pub mod alert_banner;
+pub mod alert_rules;
+pub mod export_buttons;
+pub mod top_consumers;
+pub mod deployment_history;
+pub mod build_console;
+pub mod scan_report_table;
+pub mod security_scan_card;
+pub mod auto_deploy_settings;
+pub mod searchable_select;
+pub mod github_source_picker;
\ No newline at end of file
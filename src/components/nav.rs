@@ -70,6 +70,7 @@ pub fn nav() -> Html
                             html! 
                             {
                                 <>
+                                    <li><Link<AppRoute> to={AppRoute::Templates}>{ i18n.t("nav.templates") }</Link<AppRoute>></li>
                                     if user.is_admin 
                                     {
                                         <li><Link<AppRoute> to={AppRoute::Admin}>{ i18n.t("nav.admin") }</Link<AppRoute>></li>
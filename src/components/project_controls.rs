@@ -4,8 +4,11 @@ use std::pin::Pin;
 use i18nrs::yew::use_translation;
 use yew::prelude::*;
 
+use crate::services::api_client::ApiError;
 use crate::services::project_service;
 
+use crate::pages::project_dashboard::translate_error;
+
 const RELOAD_DELAY_MS: u32 = 1500;
 
 type LocalBoxFutureAction<T> = Pin<Box<dyn Future<Output = T> + 'static>>;
@@ -23,37 +26,45 @@ pub fn project_controls(props: &ProjectControlsProps) -> Html
     let (i18n, _) = use_translation();
     let is_controlling = use_state(|| false);
     let success_message = use_state(|| None::<String>);
+    let error = use_state(|| None::<ApiError>);
 
     let create_control_callback =
-        |action: fn(i32) -> LocalBoxFutureAction<Result<(), String>>, message: String| 
+        |action: fn(i32) -> LocalBoxFutureAction<Result<(), ApiError>>, message: String| 
         {
             let is_controlling = is_controlling.clone();
             let on_update = props.on_update.clone();
             let project_id = props.project_id;
             let success_message = success_message.clone();
+            let error = error.clone();
 
             Callback::from(move |_| 
             {
                 let is_controlling = is_controlling.clone();
                 let on_update = on_update.clone();
                 let success_message = success_message.clone();
+                let error = error.clone();
                 let message = message.clone();
                 is_controlling.set(true);
                 success_message.set(None);
+                error.set(None);
 
                 wasm_bindgen_futures::spawn_local(async move 
                 {
-                    if action(project_id).await.is_ok() 
-                    {
-                        success_message.set(Some(message));
-                        gloo_timers::callback::Timeout::new(RELOAD_DELAY_MS, move || {
-                            on_update.emit(());
-                        })
-                        .forget();
-                    } 
-                    else 
+                    match action(project_id).await
                     {
-                        gloo_console::error!("Control action failed");
+                        Ok(_) =>
+                        {
+                            success_message.set(Some(message));
+                            gloo_timers::callback::Timeout::new(RELOAD_DELAY_MS, move || {
+                                on_update.emit(());
+                            })
+                            .forget();
+                        }
+                        Err(e) =>
+                        {
+                            gloo_console::error!("Control action failed:", e.error_code.clone());
+                            error.set(Some(e));
+                        }
                     }
                     is_controlling.set(false);
                 });
@@ -86,6 +97,11 @@ pub fn project_controls(props: &ProjectControlsProps) -> Html
                 </div>
             }
 
+            if let Some(err) = &*error
+            {
+                <p class="error">{ translate_error(err, &i18n) }</p>
+            }
+
             <div style="display: flex; gap: var(--spacing-md);">
                 <button class="button-primary" onclick={on_start} disabled={*is_controlling}>
                     { i18n.t("project_dashboard.start_button") }
@@ -1,8 +1,11 @@
 use i18nrs::yew::use_translation;
 use yew::prelude::*;
 
+use crate::contexts::config_context::use_config;
 use crate::contexts::sse_context::use_sse_status;
+use crate::models::manifest::ProjectManifest;
 use crate::models::project::ProjectDetails;
+use crate::utils::manifest::{download_manifest, ManifestFormat};
 
 use crate::pages::project_dashboard::{get_status_class, translate_status};
 
@@ -16,6 +19,7 @@ pub struct ProjectInfoProps
 pub fn project_info(props: &ProjectInfoProps) -> Html
 {
     let (i18n, _) = use_translation();
+    let config = use_config();
     let current_status = use_sse_status();
     let p = &props.project_details.project;
 
@@ -24,7 +28,16 @@ pub fn project_info(props: &ProjectInfoProps) -> Html
         .t("common.created_on")
         .replace("{date}", &created_at_formatted);
 
-    let project_url = format!("https://{}.hangar.garageisep.com", p.name);
+    let project_url = config.project_url(&p.name);
+
+    let on_export_manifest = |format: ManifestFormat|
+    {
+        let details = props.project_details.clone();
+        Callback::from(move |_|
+        {
+            download_manifest(&ProjectManifest::from_details(&details), format);
+        })
+    };
     
     let (status_class, status_text) = if let Some(status) = &current_status
     {
@@ -89,6 +102,16 @@ pub fn project_info(props: &ProjectInfoProps) -> Html
                     { format!("{}: {}", i18n.t("project_dashboard.persistent_volume_label"), path) }
                 </p>
             }
+
+            <div style="display: flex; align-items: center; flex-wrap: wrap; gap: var(--spacing-sm); margin-top: var(--spacing-md);">
+                <span style="color: var(--color-text-secondary);">{ i18n.t("project_dashboard.export_manifest_label") }</span>
+                <button class="metrics-range-button" onclick={on_export_manifest(ManifestFormat::Json)}>
+                    { ManifestFormat::Json.filename() }
+                </button>
+                <button class="metrics-range-button" onclick={on_export_manifest(ManifestFormat::Toml)}>
+                    { ManifestFormat::Toml.filename() }
+                </button>
+            </div>
         </div>
     }
 }
\ No newline at end of file
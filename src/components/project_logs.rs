@@ -1,100 +1,595 @@
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use i18nrs::I18n;
 use i18nrs::yew::use_translation;
+use regex::Regex;
 use yew::prelude::*;
 
-use crate::{pages::project_dashboard::render_log_line, services::project_service};
+use crate::components::virtual_list::VirtualList;
+use crate::contexts::sse_context::{use_sse_logs, LogLine, MAX_LOG_LINES};
+use crate::pages::project_dashboard::translate_error;
+use crate::services::project_service;
+use crate::services::sse_service::LogStream;
+use crate::utils::ansi::{render_message, strip_ansi};
+use crate::utils::download::download_text;
+use crate::utils::log_parser::{format_timestamp, match_ranges, timestamp_ms, LogFilter, ParsedLogLine};
 
 #[derive(Properties, PartialEq)]
-pub struct ProjectLogsProps 
+pub struct ProjectLogsProps
 {
     pub project_id: i32,
+    pub project_name: String,
+}
+
+const LOG_ROW_HEIGHT_PX: f64 = 20.0;
+
+/// Added to the sequence number of streamed lines so that their keys always sort after the snapshot's.
+const LIVE_KEY_OFFSET: u64 = 1 << 32;
+
+struct LogRow
+{
+    /// Increasing along the list, used by `VirtualList` to keep its scroll position.
+    key: u64,
+    stream: LogStream,
+    line: Rc<ParsedLogLine>,
+}
+
+/// Rows left after filtering. The snapshot is refiltered only when it or the filter changes,
+/// streamed lines are filtered once as they arrive and appended.
+struct LogView
+{
+    /// Fetch generation, first followed sequence number and filter the rows were computed for
+    source: (u32, Option<u64>, LogFilter),
+    matcher: Result<Option<Regex>, regex::Error>,
+    snapshot_rows: Vec<LogRow>,
+    snapshot_total: usize,
+    live_rows: VecDeque<LogRow>,
+    /// Streamed lines from this sequence number on have not been looked at yet
+    live_until: u64,
+    live_total: usize,
+}
+
+impl LogView
+{
+    fn new(source: (u32, Option<u64>, LogFilter), snapshot: Option<&[Rc<ParsedLogLine>]>) -> Self
+    {
+        let matcher = source.2.matcher();
+        let filter = source.2.compile(matcher.as_ref().ok().and_then(|m| m.as_ref()));
+        let snapshot = snapshot.unwrap_or_default();
+
+        let snapshot_rows = snapshot
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| filter.accepts(line))
+            .map(|(i, line)| LogRow
+            {
+                key: i as u64,
+                stream: LogStream::Stdout,
+                line: line.clone(),
+            })
+            .collect();
+
+        LogView
+        {
+            live_until: source.1.unwrap_or(0),
+            source,
+            matcher,
+            snapshot_rows,
+            snapshot_total: snapshot.len(),
+            live_rows: VecDeque::new(),
+            live_total: 0,
+        }
+    }
+
+    /// Filters the streamed lines up to `until` not seen yet, and forgets those the buffer dropped.
+    fn append_live(&mut self, lines: &VecDeque<LogLine>, until: u64)
+    {
+        let filter = self.source.2.compile(self.matcher.as_ref().ok().and_then(|m| m.as_ref()));
+
+        for l in lines.iter().skip_while(|l| l.seq < self.live_until).take_while(|l| l.seq < until)
+        {
+            if filter.accepts(&l.line)
+            {
+                self.live_rows.push_back(LogRow
+                {
+                    key: LIVE_KEY_OFFSET + l.seq,
+                    stream: l.stream,
+                    line: l.line.clone(),
+                });
+            }
+        }
+        self.live_until = self.live_until.max(until);
+
+        let first_kept = lines.front().map_or(self.live_until, |l| l.seq).max(self.source.1.unwrap_or(0));
+        while self.live_rows.front().is_some_and(|row| row.key < LIVE_KEY_OFFSET + first_kept)
+        {
+            self.live_rows.pop_front();
+        }
+        self.live_total = self.live_until.saturating_sub(first_kept) as usize;
+    }
+
+    fn len(&self) -> usize
+    {
+        self.snapshot_rows.len() + self.live_rows.len()
+    }
+
+    fn total(&self) -> usize
+    {
+        self.snapshot_total + self.live_total
+    }
+
+    fn row(&self, index: usize) -> &LogRow
+    {
+        match index.checked_sub(self.snapshot_rows.len())
+        {
+            Some(live_index) => &self.live_rows[live_index],
+            None => &self.snapshot_rows[index],
+        }
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &LogRow>
+    {
+        self.snapshot_rows.iter().chain(self.live_rows.iter())
+    }
+}
+
+fn render_log_row(row: &LogRow, matcher: Option<&Regex>, i18n: &I18n, on_select: Callback<Rc<ParsedLogLine>>) -> Html
+{
+    let line = &row.line;
+    let stream_class = match row.stream
+    {
+        LogStream::Stdout => "log-stream-stdout",
+        LogStream::Stderr => "log-stream-stderr",
+    };
+
+    html!
+    {
+        <div class={classes!("log-line", stream_class)}>
+            <span class="log-timestamp">{ format_timestamp(&line.timestamp) }</span>
+            if line.structured.is_some()
+            {
+                <span class={classes!("log-level-badge", line.level.css_class())}>
+                    { line.level.label() }
+                </span>
+            }
+            <span class={classes!("log-message", line.level.css_class())}>
+                { render_message(&line.message, &match_ranges(&line.message.text, matcher)) }
+            </span>
+            if let Some(structured) = line.structured.as_ref().filter(|s| !s.fields.is_empty())
+            {
+                <button
+                    class="log-fields-toggle"
+                    onclick={
+                        let line = line.clone();
+                        Callback::from(move |_| on_select.emit(line.clone()))
+                    }
+                >
+                    { i18n.t("project_dashboard.logs_fields_count").replace("{count}", &structured.fields.len().to_string()) }
+                </button>
+            }
+        </div>
+    }
 }
 
 #[function_component(ProjectLogs)]
-pub fn project_logs(props: &ProjectLogsProps) -> Html 
+pub fn project_logs(props: &ProjectLogsProps) -> Html
 {
     let (i18n, _) = use_translation();
-    let logs = use_state(|| None::<String>);
+    let log_buffer = use_sse_logs();
+    let logs = use_state(|| None::<Rc<Vec<Rc<ParsedLogLine>>>>);
+    // Bumped on every fetch, so the view is only recomputed when the snapshot actually changes
+    let logs_generation = use_state(|| 0_u32);
     let logs_error = use_state(|| None::<String>);
     let are_logs_loading = use_state(|| false);
+    let filter = use_state(LogFilter::default);
+    let selected_line = use_state(|| None::<Rc<ParsedLogLine>>);
+
+    // Sequence number of the first streamed line to show, `None` when not following
+    let follow_from = use_state(|| None::<u64>);
+    // Sequence number at which the display was frozen, `None` when not paused
+    let paused_at = use_state(|| None::<u64>);
 
-    let on_fetch_logs = 
+    let view = use_mut_ref(|| LogView::new((0, None, LogFilter::default()), None));
+    {
+        let source = (*logs_generation, *follow_from, (*filter).clone());
+        let mut view = view.borrow_mut();
+        if view.source != source
+        {
+            *view = LogView::new(source, logs.as_deref().map(|lines| lines.as_slice()));
+        }
+        // Nothing new to look at while paused
+        if follow_from.is_some()
+        {
+            view.append_live(&log_buffer.lines(), paused_at.unwrap_or(log_buffer.next_seq));
+        }
+    }
+
+    let buffered_while_paused = paused_at.map_or(0, |until| log_buffer.next_seq.saturating_sub(until));
+
+    let on_fetch_logs =
     {
         let logs = logs.clone();
+        let logs_generation = logs_generation.clone();
         let logs_error = logs_error.clone();
         let are_logs_loading = are_logs_loading.clone();
         let project_id = props.project_id;
         let i18n = i18n.clone();
 
-        Callback::from(move |_| 
+        Callback::from(move |_|
         {
             let logs = logs.clone();
+            let logs_generation = logs_generation.clone();
             let logs_error = logs_error.clone();
             let are_logs_loading = are_logs_loading.clone();
             let i18n = i18n.clone();
             are_logs_loading.set(true);
             logs_error.set(None);
 
-            wasm_bindgen_futures::spawn_local(async move 
+            wasm_bindgen_futures::spawn_local(async move
             {
-                match project_service::get_project_logs(project_id).await 
+                match project_service::get_project_logs(project_id).await
                 {
-                    Ok(log_data) => logs.set(Some(log_data)),
-                    Err(e) => 
+                    Ok(log_data) =>
+                    {
+                        let lines = log_data.lines().map(|l| Rc::new(ParsedLogLine::parse(l))).collect();
+                        logs.set(Some(Rc::new(lines)));
+                    }
+                    Err(e) =>
                     {
                         let error_message = i18n
                             .t("project_dashboard.logs_error")
-                            .replace("{error}", &e);
+                            .replace("{error}", &translate_error(&e, &i18n));
                         logs_error.set(Some(error_message));
                         logs.set(None);
                     }
                 }
+                logs_generation.set(*logs_generation + 1);
                 are_logs_loading.set(false);
             });
         })
     };
 
-    html! 
+    let on_toggle_follow =
+    {
+        let follow_from = follow_from.clone();
+        let paused_at = paused_at.clone();
+        let next_seq = log_buffer.next_seq;
+
+        Callback::from(move |_|
+        {
+            if follow_from.is_some()
+            {
+                follow_from.set(None);
+            }
+            else
+            {
+                follow_from.set(Some(next_seq));
+            }
+            paused_at.set(None);
+        })
+    };
+
+    let on_toggle_pause =
+    {
+        let paused_at = paused_at.clone();
+        let next_seq = log_buffer.next_seq;
+
+        Callback::from(move |_|
+        {
+            if paused_at.is_some()
+            {
+                paused_at.set(None);
+            }
+            else
+            {
+                paused_at.set(Some(next_seq));
+            }
+        })
+    };
+
+    let update_filter = |apply: fn(&mut LogFilter, &web_sys::HtmlInputElement)|
+    {
+        let filter = filter.clone();
+        Callback::from(move |e: Event|
+        {
+            let input = e.target_unchecked_into::<web_sys::HtmlInputElement>();
+            let mut next = (*filter).clone();
+            apply(&mut next, &input);
+            filter.set(next);
+        })
+    };
+
+    let on_query_input =
+    {
+        let filter = filter.clone();
+        Callback::from(move |e: InputEvent|
+        {
+            let mut next = (*filter).clone();
+            next.query = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+            filter.set(next);
+        })
+    };
+
+    let on_fields_input =
+    {
+        let filter = filter.clone();
+        Callback::from(move |e: InputEvent|
+        {
+            let mut next = (*filter).clone();
+            next.fields = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+            filter.set(next);
+        })
+    };
+
+    let on_download =
+    {
+        let view = view.clone();
+        let filename = format!("{}-logs.log", props.project_name);
+
+        Callback::from(move |_|
+        {
+            let content = view
+                .borrow()
+                .rows()
+                .map(|row| strip_ansi(&row.line.raw))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            if let Err(e) = download_text(&filename, &content, "text/plain")
+            {
+                gloo_console::error!("Failed to download logs:", e);
+            }
+        })
+    };
+
+    let on_select_line =
+    {
+        let selected_line = selected_line.clone();
+        Callback::from(move |line: Rc<ParsedLogLine>| selected_line.set(Some(line)))
+    };
+
+    let on_close_fields =
+    {
+        let selected_line = selected_line.clone();
+        Callback::from(move |_| selected_line.set(None))
+    };
+
+    let render_row =
+    {
+        let view = view.clone();
+        let i18n = i18n.clone();
+
+        Callback::from(move |index: usize|
+        {
+            let view = view.borrow();
+            let matcher = view.matcher.as_ref().ok().and_then(|m| m.as_ref());
+            render_log_row(view.row(index), matcher, &i18n, on_select_line.clone())
+        })
+    };
+
+    let row_key =
+    {
+        let view = view.clone();
+        Callback::from(move |index: usize| view.borrow().row(index).key)
+    };
+
+    let is_following = follow_from.is_some();
+    let (row_count, total_count) = (view.borrow().len(), view.borrow().total());
+    let regex_error = view.borrow().matcher.as_ref().err().map(|e| e.to_string());
+
+    html!
     {
         <div class="card" style="margin-top: var(--spacing-lg);">
-            <div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: var(--spacing-md);">
+            <div style="display: flex; justify-content: space-between; align-items: center; flex-wrap: wrap; gap: var(--spacing-sm); margin-bottom: var(--spacing-md);">
                 <h2>{ i18n.t("project_dashboard.card_title_logs") }</h2>
-                <button class="button-primary" onclick={on_fetch_logs} disabled={*are_logs_loading}>
+                <div style="display: flex; gap: var(--spacing-sm);">
+                    if is_following
                     {
-                        if *are_logs_loading 
+                        <button class="button-primary" onclick={on_toggle_pause}>
+                            {
+                                if paused_at.is_some()
+                                {
+                                    i18n.t("project_dashboard.resume_logs_button")
+                                }
+                                else
+                                {
+                                    i18n.t("project_dashboard.pause_logs_button")
+                                }
+                            }
+                        </button>
+                    }
+                    <button class="button-primary" onclick={on_toggle_follow}>
                         {
-                            i18n.t("project_dashboard.fetch_logs_loading")
-                        } 
-                        else 
+                            if is_following
+                            {
+                                i18n.t("project_dashboard.unfollow_logs_button")
+                            }
+                            else
+                            {
+                                i18n.t("project_dashboard.follow_logs_button")
+                            }
+                        }
+                    </button>
+                    <button class="button-primary" onclick={on_fetch_logs} disabled={*are_logs_loading}>
                         {
-                            i18n.t("project_dashboard.fetch_logs_button")
+                            if *are_logs_loading
+                            {
+                                i18n.t("project_dashboard.fetch_logs_loading")
+                            }
+                            else
+                            {
+                                i18n.t("project_dashboard.fetch_logs_button")
+                            }
                         }
-                    }
+                    </button>
+                </div>
+            </div>
+
+            <div class="logs-toolbar">
+                <input
+                    type="text"
+                    class="text-input"
+                    placeholder={i18n.t("project_dashboard.logs_search_placeholder")}
+                    value={filter.query.clone()}
+                    oninput={on_query_input}
+                />
+                <input
+                    type="text"
+                    class="text-input"
+                    placeholder={i18n.t("project_dashboard.logs_fields_placeholder")}
+                    value={filter.fields.clone()}
+                    oninput={on_fields_input}
+                />
+                <label class="checkbox-label">
+                    <input
+                        type="checkbox"
+                        checked={filter.use_regex}
+                        onchange={update_filter(|f, input| f.use_regex = input.checked())}
+                    />
+                    { i18n.t("project_dashboard.logs_regex") }
+                </label>
+                <label class="checkbox-label">
+                    <input
+                        type="checkbox"
+                        checked={filter.show_error}
+                        onchange={update_filter(|f, input| f.show_error = input.checked())}
+                    />
+                    { i18n.t("project_dashboard.logs_level_error") }
+                </label>
+                <label class="checkbox-label">
+                    <input
+                        type="checkbox"
+                        checked={filter.show_warn}
+                        onchange={update_filter(|f, input| f.show_warn = input.checked())}
+                    />
+                    { i18n.t("project_dashboard.logs_level_warn") }
+                </label>
+                <label class="checkbox-label">
+                    <input
+                        type="checkbox"
+                        checked={filter.show_info}
+                        onchange={update_filter(|f, input| f.show_info = input.checked())}
+                    />
+                    { i18n.t("project_dashboard.logs_level_info") }
+                </label>
+                <label class="checkbox-label">
+                    { i18n.t("project_dashboard.logs_from") }
+                    <input
+                        type="datetime-local"
+                        class="text-input"
+                        onchange={update_filter(|f, input| f.from_ms = timestamp_ms(&input.value()))}
+                    />
+                </label>
+                <label class="checkbox-label">
+                    { i18n.t("project_dashboard.logs_to") }
+                    <input
+                        type="datetime-local"
+                        class="text-input"
+                        onchange={update_filter(|f, input| f.to_ms = timestamp_ms(&input.value()))}
+                    />
+                </label>
+                <button class="button-primary" onclick={on_download} disabled={row_count == 0}>
+                    { i18n.t("project_dashboard.logs_download_button") }
                 </button>
             </div>
 
-            <div class="logs-container">
-                {
-                    if let Some(err_msg) = &*logs_error 
+            if let Some(e) = &regex_error
+            {
+                <p class="error">
+                    { i18n.t("project_dashboard.logs_invalid_regex").replace("{error}", e) }
+                </p>
+            }
+
+            if total_count > 0
+            {
+                <p style="color: var(--color-text-secondary); margin-bottom: var(--spacing-sm);">
                     {
-                        html! { <p class="error">{ err_msg }</p> }
-                    } 
-                    else if let Some(log_data) = &*logs 
+                        i18n.t("project_dashboard.logs_visible_count")
+                            .replace("{visible}", &row_count.to_string())
+                            .replace("{total}", &total_count.to_string())
+                    }
+                </p>
+            }
+
+            if buffered_while_paused > 0
+            {
+                <p style="color: var(--color-text-secondary); margin-bottom: var(--spacing-sm);">
                     {
-                        if log_data.is_empty() 
+                        i18n.t("project_dashboard.logs_paused_hint")
+                            .replace("{count}", &buffered_while_paused.min(MAX_LOG_LINES as u64).to_string())
+                    }
+                </p>
+            }
+
+            if let Some(err_msg) = &*logs_error
+            {
+                <div class="logs-container">
+                    <p class="error">{ err_msg }</p>
+                </div>
+            }
+            else if row_count > 0
+            {
+                <VirtualList
+                    class="logs-container"
+                    row_count={row_count}
+                    row_height={LOG_ROW_HEIGHT_PX}
+                    render_row={render_row}
+                    row_key={row_key}
+                    follow={is_following && paused_at.is_none()}
+                />
+            }
+            else
+            {
+                <div class="logs-container">
+                    <div class="placeholder">
                         {
-                            html! { <div class="placeholder">{ i18n.t("project_dashboard.logs_empty") }</div> }
-                        } 
-                        else 
+                            if total_count > 0
+                            {
+                                i18n.t("project_dashboard.logs_no_match")
+                            }
+                            else if is_following
+                            {
+                                i18n.t("project_dashboard.logs_waiting")
+                            }
+                            else if logs.is_some()
+                            {
+                                i18n.t("project_dashboard.logs_empty")
+                            }
+                            else
+                            {
+                                i18n.t("project_dashboard.logs_placeholder")
+                            }
+                        }
+                    </div>
+                </div>
+            }
+
+            if let Some(line) = &*selected_line
+            {
+                <div class="log-fields-panel">
+                    <div class="log-fields-panel-header">
+                        <span class={classes!("log-level-badge", line.level.css_class())}>
+                            { line.level.label() }
+                        </span>
+                        <span class="log-fields-panel-message">{ &line.message.text }</span>
+                        <button class="button-primary" onclick={on_close_fields}>
+                            { i18n.t("project_dashboard.logs_fields_close") }
+                        </button>
+                    </div>
+                    <dl>
                         {
-                            log_data.lines().map(render_log_line).collect::<Html>()
+                            for line.structured.iter().flat_map(|s| s.fields.iter()).map(|(key, value)| html!
+                            {
+                                <>
+                                    <dt>{ key }</dt>
+                                    <dd>{ value }</dd>
+                                </>
+                            })
                         }
-                    } 
-                    else 
-                    {
-                        html! { <div class="placeholder">{ i18n.t("project_dashboard.logs_placeholder") }</div> }
-                    }
-                }
-            </div>
+                    </dl>
+                </div>
+            }
         </div>
     }
 }
\ No newline at end of file
@@ -1,19 +1,98 @@
 use i18nrs::yew::use_translation;
+use serde::Serialize;
 use yew::prelude::*;
 
+use crate::components::export_buttons::ExportButtons;
 use crate::components::gauge::Gauge;
-use crate::contexts::sse_context::use_sse_metrics;
+use crate::components::metrics_chart::MetricsChart;
+use crate::contexts::sse_context::{use_sse_metrics, use_sse_metrics_history};
+use crate::utils::export::{download_rows, iso_timestamp, ExportFormat};
+use crate::utils::units::format_bytes_rate;
+
+const HISTORY_RANGES_MINUTES: [u32; 3] = [5, 15, 60];
+const BYTES_PER_MIB: f64 = 1024.0 * 1024.0;
+const EXPORT_COLUMNS: [&str; 4] = ["timestamp", "cpu_usage", "memory_usage", "memory_limit"];
+
+#[derive(Serialize)]
+struct MetricsExportRow
+{
+    timestamp: String,
+    cpu_usage: f64,
+    memory_usage: f64,
+    memory_limit: f64,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ProjectMetricsProps
+{
+    pub project_name: String,
+}
 
 #[function_component(ProjectMetrics)]
-pub fn project_metrics() -> Html
+pub fn project_metrics(props: &ProjectMetricsProps) -> Html
 {
     let (i18n, _) = use_translation();
     let current_metrics = use_sse_metrics();
+    let history = use_sse_metrics_history();
+    let range_minutes = use_state(|| 15_u32);
+
+    let now_ms = js_sys::Date::now();
+    let window_ms = f64::from(*range_minutes) * 60_000.0;
+    let in_range = history
+        .points
+        .iter()
+        .filter(|p| p.at_ms >= now_ms - window_ms)
+        .collect::<Vec<_>>();
+
+    let cpu_points = in_range
+        .iter()
+        .map(|p| (p.at_ms, p.metrics.cpu_usage))
+        .collect::<Vec<_>>();
+    // CPU usage goes past 100 % when a container uses more than one core
+    let cpu_max = cpu_points
+        .iter()
+        .map(|&(_, cpu)| cpu)
+        .fold(100.0, f64::max);
+
+    let memory_points = in_range
+        .iter()
+        .map(|p| (p.at_ms, p.metrics.memory_usage / BYTES_PER_MIB))
+        .collect::<Vec<_>>();
+    let memory_max = in_range
+        .iter()
+        .map(|p| p.metrics.memory_limit / BYTES_PER_MIB)
+        .fold(0.0, f64::max);
+
+    let on_export =
+    {
+        let history = history.clone();
+        let filename_stem = format!("{}-metrics", props.project_name);
+
+        Callback::from(move |format: ExportFormat|
+        {
+            let rows = history
+                .points
+                .iter()
+                .map(|p| MetricsExportRow
+                {
+                    timestamp: iso_timestamp(p.at_ms),
+                    cpu_usage: p.metrics.cpu_usage,
+                    memory_usage: p.metrics.memory_usage,
+                    memory_limit: p.metrics.memory_limit,
+                })
+                .collect::<Vec<_>>();
+
+            download_rows(&filename_stem, &rows, &EXPORT_COLUMNS, format);
+        })
+    };
 
     html!
     {
         <div class="card" style="margin-top: var(--spacing-lg);">
-            <h2>{ i18n.t("project_dashboard.card_title_metrics") }</h2>
+            <div style="display: flex; justify-content: space-between; align-items: center; flex-wrap: wrap; gap: var(--spacing-sm);">
+                <h2>{ i18n.t("project_dashboard.card_title_metrics") }</h2>
+                <ExportButtons on_export={on_export} disabled={history.points.is_empty()} />
+            </div>
             <div class="metrics-grid">
                 {
                     if let Some(m) = &current_metrics
@@ -42,6 +121,81 @@ pub fn project_metrics() -> Html
                     }
                 }
             </div>
+
+            if let Some(m) = &current_metrics
+            {
+                <div class="metrics-grid" style="justify-items: start; margin-top: var(--spacing-lg);">
+                    <p>
+                        <strong>{ i18n.t("project_dashboard.metrics_network_rx") }</strong>
+                        <span class="detail-value">{ format_bytes_rate(m.network_rx_bytes_per_sec) }</span>
+                    </p>
+                    <p>
+                        <strong>{ i18n.t("project_dashboard.metrics_network_tx") }</strong>
+                        <span class="detail-value">{ format_bytes_rate(m.network_tx_bytes_per_sec) }</span>
+                    </p>
+                    <p>
+                        <strong>{ i18n.t("project_dashboard.metrics_block_read") }</strong>
+                        <span class="detail-value">{ format_bytes_rate(m.block_read_bytes_per_sec) }</span>
+                    </p>
+                    <p>
+                        <strong>{ i18n.t("project_dashboard.metrics_block_write") }</strong>
+                        <span class="detail-value">{ format_bytes_rate(m.block_write_bytes_per_sec) }</span>
+                    </p>
+                    <p>
+                        <strong>{ i18n.t("project_dashboard.metrics_pids") }</strong>
+                        <span class="detail-value">{ m.pids }</span>
+                    </p>
+                </div>
+            }
+
+            <div class="metrics-history-header">
+                <h3>{ i18n.t("project_dashboard.metrics_history_title") }</h3>
+                <div class="metrics-range-selector">
+                    {
+                        for HISTORY_RANGES_MINUTES.into_iter().map(|minutes|
+                        {
+                            let range_minutes = range_minutes.clone();
+                            let is_active = *range_minutes == minutes;
+
+                            html!
+                            {
+                                <button
+                                    class={classes!("metrics-range-button", is_active.then_some("active"))}
+                                    onclick={Callback::from(move |_| range_minutes.set(minutes))}
+                                >
+                                    { i18n.t("project_dashboard.metrics_range_minutes").replace("{minutes}", &minutes.to_string()) }
+                                </button>
+                            }
+                        })
+                    }
+                </div>
+            </div>
+
+            if in_range.len() < 2
+            {
+                <p style="color: var(--color-text-secondary);">{ i18n.t("project_dashboard.metrics_history_empty") }</p>
+            }
+            else
+            {
+                <div class="metrics-charts">
+                    <MetricsChart
+                        label="CPU"
+                        points={cpu_points}
+                        now_ms={now_ms}
+                        window_ms={window_ms}
+                        y_max={cpu_max}
+                        unit="%"
+                    />
+                    <MetricsChart
+                        label="RAM"
+                        points={memory_points}
+                        now_ms={now_ms}
+                        window_ms={window_ms}
+                        y_max={memory_max}
+                        unit="MiB"
+                    />
+                </div>
+            }
         </div>
     }
 }
\ No newline at end of file
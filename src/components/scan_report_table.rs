@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+use crate::models::scan::{Severity, Vulnerability};
+
+#[derive(Clone, Copy, PartialEq)]
+enum SortColumn
+{
+    Severity,
+    Id,
+    Package,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ScanReportTableProps
+{
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+/// Vulnerabilities found in an image, most severe first, filterable by severity.
+#[function_component(ScanReportTable)]
+pub fn scan_report_table(props: &ScanReportTableProps) -> Html
+{
+    let (i18n, _) = use_translation();
+    let sort = use_state(|| (SortColumn::Severity, false));
+    let hidden = use_state(HashSet::<Severity>::new);
+
+    let (column, descending) = *sort;
+
+    let mut vulnerabilities = props
+        .vulnerabilities
+        .iter()
+        .filter(|v| !hidden.contains(&v.severity))
+        .collect::<Vec<_>>();
+
+    vulnerabilities.sort_by(|a, b|
+    {
+        let ordering = match column
+        {
+            SortColumn::Severity => a.severity.cmp(&b.severity).then_with(|| a.package.cmp(&b.package)),
+            SortColumn::Id => a.id.cmp(&b.id),
+            SortColumn::Package => a.package.cmp(&b.package).then_with(|| a.severity.cmp(&b.severity)),
+        };
+        if descending { ordering.reverse() } else { ordering }
+    });
+
+    let header = |target: SortColumn, label: String|
+    {
+        let sort = sort.clone();
+        let on_click = Callback::from(move |_|
+        {
+            let (current, descending) = *sort;
+            sort.set((target, current == target && !descending));
+        });
+
+        let arrow = match (column == target, descending)
+        {
+            (true, false) => " ▲",
+            (true, true) => " ▼",
+            (false, _) => "",
+        };
+
+        html!
+        {
+            <th class="sortable" onclick={on_click}>{ format!("{}{}", label, arrow) }</th>
+        }
+    };
+
+    let severity_filter = |severity: Severity|
+    {
+        let count = props.vulnerabilities.iter().filter(|v| v.severity == severity).count();
+        if count == 0
+        {
+            return html! {};
+        }
+
+        let is_shown = !hidden.contains(&severity);
+        let on_toggle =
+        {
+            let hidden = hidden.clone();
+            Callback::from(move |_|
+            {
+                let mut next = (*hidden).clone();
+                if !next.remove(&severity)
+                {
+                    next.insert(severity);
+                }
+                hidden.set(next);
+            })
+        };
+
+        html!
+        {
+            <button
+                type="button"
+                class={classes!("severity-filter", severity.css_class(), (!is_shown).then_some("severity-filter-off"))}
+                onclick={on_toggle}
+                aria-pressed={is_shown.to_string()}
+            >
+                { format!("{} ({})", i18n.t(severity.translation_key()), count) }
+            </button>
+        }
+    };
+
+    html!
+    {
+        <div class="scan-report">
+            if props.vulnerabilities.is_empty()
+            {
+                <p>{ i18n.t("project_dashboard.scan_no_vulnerabilities") }</p>
+            }
+            else
+            {
+                <div class="severity-filters">
+                    { for Severity::ALL.into_iter().map(severity_filter) }
+                </div>
+
+                <div class="scan-report-table">
+                    <table class="data-table">
+                        <thead>
+                            <tr>
+                                { header(SortColumn::Severity, i18n.t("project_dashboard.scan_severity")) }
+                                { header(SortColumn::Id, i18n.t("project_dashboard.scan_vulnerability")) }
+                                { header(SortColumn::Package, i18n.t("project_dashboard.scan_package")) }
+                                <th>{ i18n.t("project_dashboard.scan_installed_version") }</th>
+                                <th>{ i18n.t("project_dashboard.scan_fixed_version") }</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {
+                                for vulnerabilities.iter().map(|v| html!
+                                {
+                                    <tr>
+                                        <td>
+                                            <span class={classes!("status-badge", v.severity.css_class())}>
+                                                { i18n.t(v.severity.translation_key()) }
+                                            </span>
+                                        </td>
+                                        <td><code>{ &v.id }</code></td>
+                                        <td>{ &v.package }</td>
+                                        <td><code>{ &v.installed_version }</code></td>
+                                        <td>
+                                            if let Some(fixed) = &v.fixed_version
+                                            {
+                                                <code>{ fixed }</code>
+                                            }
+                                            else
+                                            {
+                                                <span class="scan-no-fix">{ i18n.t("project_dashboard.scan_no_fix") }</span>
+                                            }
+                                        </td>
+                                    </tr>
+                                })
+                            }
+                        </tbody>
+                    </table>
+                </div>
+
+                if vulnerabilities.is_empty()
+                {
+                    <p class="scan-no-fix">{ i18n.t("project_dashboard.scan_all_filtered") }</p>
+                }
+            }
+        </div>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,177 @@
+use yew::prelude::*;
+
+/// Options shown at once, the query narrows the rest down.
+const MAX_VISIBLE_OPTIONS: usize = 50;
+
+#[derive(Properties, PartialEq)]
+pub struct SearchableSelectProps
+{
+    pub id: String,
+    pub options: Vec<String>,
+    /// Currently selected option, shown while the list is closed
+    pub value: String,
+    pub on_select: Callback<String>,
+    #[prop_or_default]
+    pub placeholder: String,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub invalid: bool,
+}
+
+/// Text input that filters `options` as the user types and only ever emits one of them.
+#[function_component(SearchableSelect)]
+pub fn searchable_select(props: &SearchableSelectProps) -> Html
+{
+    let query = use_state(String::new);
+    let is_open = use_state(|| false);
+    let highlighted = use_state(|| 0_usize);
+
+    let needle = query.to_lowercase();
+    let filtered = props
+        .options
+        .iter()
+        .filter(|o| o.to_lowercase().contains(&needle))
+        .take(MAX_VISIBLE_OPTIONS)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let select =
+    {
+        let on_select = props.on_select.clone();
+        let is_open = is_open.clone();
+        let query = query.clone();
+
+        Callback::from(move |option: String|
+        {
+            is_open.set(false);
+            query.set(String::new());
+            on_select.emit(option);
+        })
+    };
+
+    let on_focus =
+    {
+        let is_open = is_open.clone();
+        let query = query.clone();
+        let highlighted = highlighted.clone();
+
+        Callback::from(move |_: FocusEvent|
+        {
+            query.set(String::new());
+            highlighted.set(0);
+            is_open.set(true);
+        })
+    };
+
+    let on_blur =
+    {
+        let is_open = is_open.clone();
+        Callback::from(move |_: FocusEvent| is_open.set(false))
+    };
+
+    let on_input =
+    {
+        let query = query.clone();
+        let is_open = is_open.clone();
+        let highlighted = highlighted.clone();
+
+        Callback::from(move |e: InputEvent|
+        {
+            query.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value());
+            highlighted.set(0);
+            is_open.set(true);
+        })
+    };
+
+    let on_keydown =
+    {
+        let highlighted = highlighted.clone();
+        let is_open = is_open.clone();
+        let filtered = filtered.clone();
+        let select = select.clone();
+
+        Callback::from(move |e: KeyboardEvent|
+        {
+            match e.key().as_str()
+            {
+                "ArrowDown" =>
+                {
+                    e.prevent_default();
+                    is_open.set(true);
+                    highlighted.set((*highlighted + 1).min(filtered.len().saturating_sub(1)));
+                }
+                "ArrowUp" =>
+                {
+                    e.prevent_default();
+                    highlighted.set(highlighted.saturating_sub(1));
+                }
+                "Enter" if *is_open =>
+                {
+                    // Picks the option instead of submitting the surrounding form
+                    e.prevent_default();
+                    if let Some(option) = filtered.get(*highlighted)
+                    {
+                        select.emit(option.clone());
+                    }
+                }
+                "Escape" => is_open.set(false),
+                _ => {}
+            }
+        })
+    };
+
+    html!
+    {
+        <div class="searchable-select">
+            <input
+                type="text"
+                id={props.id.clone()}
+                class={classes!("text-input", props.invalid.then_some("input-invalid"))}
+                autocomplete="off"
+                role="combobox"
+                aria-expanded={is_open.to_string()}
+                placeholder={props.placeholder.clone()}
+                value={if *is_open { (*query).clone() } else { props.value.clone() }}
+                onfocus={on_focus}
+                onblur={on_blur}
+                oninput={on_input}
+                onkeydown={on_keydown}
+                disabled={props.disabled}
+            />
+
+            if *is_open && !filtered.is_empty()
+            {
+                <ul class="searchable-select-options" role="listbox">
+                    {
+                        for filtered.iter().enumerate().map(|(i, option)|
+                        {
+                            let on_mouse_down =
+                            {
+                                let select = select.clone();
+                                let option = option.clone();
+                                // mousedown fires before the input loses focus and closes the list
+                                Callback::from(move |e: MouseEvent|
+                                {
+                                    e.prevent_default();
+                                    select.emit(option.clone());
+                                })
+                            };
+
+                            html!
+                            {
+                                <li
+                                    role="option"
+                                    class={classes!((i == *highlighted).then_some("highlighted"), (*option == props.value).then_some("selected"))}
+                                    onmousedown={on_mouse_down}
+                                >
+                                    { option }
+                                </li>
+                            }
+                        })
+                    }
+                </ul>
+            }
+        </div>
+    }
+}
\ No newline at end of file
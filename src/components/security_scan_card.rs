@@ -0,0 +1,82 @@
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+use crate::components::scan_report_table::ScanReportTable;
+use crate::contexts::sse_context::use_sse_deployment;
+use crate::models::scan::ScanReport;
+use crate::pages::project_dashboard::translate_error;
+use crate::services::api_client::ApiError;
+use crate::services::project_service;
+use crate::services::sse_service::DeploymentStage;
+use crate::utils::log_parser::format_timestamp;
+
+#[derive(Properties, PartialEq)]
+pub struct SecurityScanCardProps
+{
+    pub project_id: i32,
+}
+
+/// Scan report of the deployed image, refreshed whenever a deployment scans a new one.
+/// Must be rendered inside an `SseProvider`.
+#[function_component(SecurityScanCard)]
+pub fn security_scan_card(props: &SecurityScanCardProps) -> Html
+{
+    let (i18n, _) = use_translation();
+    let deployment_stage = use_sse_deployment();
+    let report = use_state(|| None::<Option<ScanReport>>);
+    let error = use_state(|| None::<ApiError>);
+
+    // A deployment cancelled after the scan leaves a new report behind, like a failed one
+    let scanned = matches!(deployment_stage, Some(DeploymentStage::ImageScanned | DeploymentStage::Failed { .. } | DeploymentStage::Cancelled));
+
+    {
+        let report = report.clone();
+        let error = error.clone();
+
+        use_effect_with((props.project_id, scanned), move |&(project_id, _)|
+        {
+            wasm_bindgen_futures::spawn_local(async move
+            {
+                match project_service::get_project_scan_report(project_id).await
+                {
+                    Ok(r) =>
+                    {
+                        error.set(None);
+                        report.set(Some(Some(r)));
+                    }
+                    // Images deployed before scanning was enabled have no report
+                    Err(e) if e.error_code == "NOT_FOUND" => report.set(Some(None)),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+            || ()
+        });
+    }
+
+    let body = match (&*error, &*report)
+    {
+        (Some(e), _) => html! { <p class="error">{ translate_error(e, &i18n) }</p> },
+        (None, None) => html! { <p>{ i18n.t("common.loading") }</p> },
+        (None, Some(None)) => html!
+        {
+            <p style="color: var(--color-text-secondary);">{ i18n.t("project_dashboard.scan_unavailable") }</p>
+        },
+        (None, Some(Some(report))) => html!
+        {
+            <>
+                <p style="color: var(--color-text-secondary);">
+                    { i18n.t("project_dashboard.scan_scanned_at").replace("{date}", &format_timestamp(&report.scanned_at).replace('T', " ")) }
+                </p>
+                <ScanReportTable vulnerabilities={report.vulnerabilities.clone()} />
+            </>
+        },
+    };
+
+    html!
+    {
+        <div class="card" style="margin-top: var(--spacing-lg);">
+            <h2>{ i18n.t("project_dashboard.card_title_security_scan") }</h2>
+            { body }
+        </div>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+use crate::contexts::sse_context::{use_sse_connection, use_sse_reconnect};
+use crate::services::sse_service::SseConnectionStatus;
+
+#[function_component(SseConnectionBadge)]
+pub fn sse_connection_badge() -> Html
+{
+    let (i18n, _) = use_translation();
+    let status = use_sse_connection();
+    let reconnect = use_sse_reconnect();
+
+    let (status_class, label) = match status
+    {
+        SseConnectionStatus::Connecting =>
+        (
+            "status_created",
+            i18n.t("project_dashboard.live_connecting"),
+        ),
+        SseConnectionStatus::Connected =>
+        (
+            "status_running",
+            i18n.t("project_dashboard.live_connected"),
+        ),
+        SseConnectionStatus::Reconnecting(attempt) =>
+        (
+            "status_restarting",
+            i18n.t("project_dashboard.live_reconnecting").replace("{attempt}", &attempt.to_string()),
+        ),
+        SseConnectionStatus::Failed =>
+        (
+            "status_dead",
+            i18n.t("project_dashboard.live_failed"),
+        ),
+    };
+
+    html!
+    {
+        <>
+            <span class={classes!("status-badge", status_class)}>
+                { label }
+            </span>
+            // Automatic retries have given up: only the user can start them again
+            if status == SseConnectionStatus::Failed
+            {
+                <button class="sse-reconnect-button" onclick={reconnect.reform(|_| ())}>
+                    { i18n.t("project_dashboard.live_reconnect") }
+                </button>
+            }
+        </>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,119 @@
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+use yew_router::prelude::Link;
+
+use crate::models::project::ProjectResourceUsage;
+use crate::router::AppRoute;
+use crate::utils::units::format_bytes;
+
+#[derive(Clone, Copy, PartialEq)]
+enum SortColumn
+{
+    Cpu,
+    Memory,
+    MemoryPercent,
+}
+
+fn memory_percent(usage: &ProjectResourceUsage) -> f64
+{
+    if usage.metrics.memory_limit > 0.0
+    {
+        usage.metrics.memory_usage / usage.metrics.memory_limit * 100.0
+    }
+    else
+    {
+        0.0
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TopConsumersProps
+{
+    pub projects: Vec<ProjectResourceUsage>,
+}
+
+/// Projects ranked by resource usage, heaviest first unless the user flips the order.
+#[function_component(TopConsumers)]
+pub fn top_consumers(props: &TopConsumersProps) -> Html
+{
+    let (i18n, _) = use_translation();
+    let sort = use_state(|| (SortColumn::Cpu, true));
+
+    let (column, descending) = *sort;
+    let sort_value = |usage: &ProjectResourceUsage| match column
+    {
+        SortColumn::Cpu => usage.metrics.cpu_usage,
+        SortColumn::Memory => usage.metrics.memory_usage,
+        SortColumn::MemoryPercent => memory_percent(usage),
+    };
+
+    let mut projects = props.projects.iter().collect::<Vec<_>>();
+    projects.sort_by(|a, b|
+    {
+        let ordering = sort_value(a).total_cmp(&sort_value(b));
+        if descending { ordering.reverse() } else { ordering }
+    });
+
+    let header = |target: SortColumn, label: String|
+    {
+        let sort = sort.clone();
+        let on_click = Callback::from(move |_|
+        {
+            let (current, descending) = *sort;
+            // Clicking the active column flips the order, another column starts heaviest first
+            sort.set((target, if current == target { !descending } else { true }));
+        });
+
+        let arrow = match (column == target, descending)
+        {
+            (true, true) => " ▼",
+            (true, false) => " ▲",
+            (false, _) => "",
+        };
+
+        html!
+        {
+            <th class="sortable" onclick={on_click}>{ format!("{}{}", label, arrow) }</th>
+        }
+    };
+
+    html!
+    {
+        if props.projects.is_empty()
+        {
+            <p>{ i18n.t("admin.top_consumers_empty") }</p>
+        }
+        else
+        {
+            <table class="data-table">
+                <thead>
+                    <tr>
+                        <th>{ i18n.t("admin.top_consumers_project") }</th>
+                        <th>{ i18n.t("common.owner") }</th>
+                        { header(SortColumn::Cpu, i18n.t("admin.top_consumers_cpu")) }
+                        { header(SortColumn::Memory, i18n.t("admin.top_consumers_memory")) }
+                        { header(SortColumn::MemoryPercent, i18n.t("admin.top_consumers_memory_percent")) }
+                    </tr>
+                </thead>
+                <tbody>
+                    {
+                        for projects.iter().map(|usage| html!
+                        {
+                            <tr>
+                                <td>
+                                    <Link<AppRoute> to={AppRoute::ProjectDashboard { id: usage.project_id }}>
+                                        <strong>{ &usage.project_name }</strong>
+                                    </Link<AppRoute>>
+                                </td>
+                                <td>{ &usage.owner }</td>
+                                <td>{ format!("{:.1} %", usage.metrics.cpu_usage) }</td>
+                                <td>{ format_bytes(usage.metrics.memory_usage) }</td>
+                                <td>{ format!("{:.1} %", memory_percent(usage)) }</td>
+                            </tr>
+                        })
+                    }
+                </tbody>
+            </table>
+        }
+    }
+}
\ No newline at end of file
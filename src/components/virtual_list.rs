@@ -0,0 +1,164 @@
+use yew::prelude::*;
+
+/// Rows rendered above and below the viewport, so that fast scrolling does not flash blank space.
+const OVERSCAN_ROWS: usize = 20;
+
+/// Below this distance from the bottom, the list still counts as scrolled to the end.
+const FOLLOW_THRESHOLD_PX: i32 = 40;
+
+#[derive(Properties, PartialEq)]
+pub struct VirtualListProps
+{
+    pub row_count: usize,
+    /// Every row must be exactly this tall, in pixels.
+    pub row_height: f64,
+    pub render_row: Callback<usize, Html>,
+    /// Strictly increasing identifier of each row. The row at the top of the viewport is
+    /// found again by key after an update, so rows dropped above it do not move the view.
+    pub row_key: Callback<usize, u64>,
+    /// Keeps the last row in view as rows are appended, until the user scrolls away from it.
+    #[prop_or_default]
+    pub follow: bool,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Scrollable list that only puts the rows around the viewport in the DOM.
+#[function_component(VirtualList)]
+pub fn virtual_list(props: &VirtualListProps) -> Html
+{
+    let container_ref = use_node_ref();
+    let scroll_top = use_state(|| 0.0_f64);
+    let viewport_height = use_state(|| 0.0_f64);
+    // Distance from the top of the scrollable area to the first row, i.e. the container's padding
+    let content_offset = use_state(|| 0.0_f64);
+    // Key of the row at the top of the viewport and how far into it the view starts
+    let anchor = use_mut_ref(|| None::<(u64, f64)>);
+    // Whether the user left the view at the end of the list, `follow` only applies then
+    let at_bottom = use_mut_ref(|| true);
+
+    {
+        let at_bottom = at_bottom.clone();
+        use_effect_with(props.follow, move |_|
+        {
+            *at_bottom.borrow_mut() = true;
+            || ()
+        });
+    }
+
+    let first_key = (props.row_count > 0).then(|| props.row_key.emit(0));
+
+    {
+        let container_ref = container_ref.clone();
+        let viewport_height = viewport_height.clone();
+        let content_offset = content_offset.clone();
+        let anchor = anchor.clone();
+        let at_bottom = at_bottom.clone();
+        let row_key = props.row_key.clone();
+        let row_count = props.row_count;
+        let row_height = props.row_height;
+
+        use_effect_with((row_count, first_key, props.follow), move |&(row_count, _, follow)|
+        {
+            if let Some(container) = container_ref.cast::<web_sys::Element>()
+            {
+                let offset_top = measure_content_offset(&container);
+                viewport_height.set(container.client_height() as f64);
+                content_offset.set(offset_top);
+
+                if follow && *at_bottom.borrow()
+                {
+                    container.set_scroll_top(container.scroll_height());
+                }
+                else if let Some((key, offset)) = *anchor.borrow()
+                {
+                    let index = partition_point(row_count, |i| row_key.emit(i) < key);
+                    let target = offset_top + index as f64 * row_height + offset;
+
+                    if (target - container.scroll_top() as f64).abs() >= 1.0
+                    {
+                        container.set_scroll_top(target as i32);
+                    }
+                }
+            }
+            || ()
+        });
+    }
+
+    let on_scroll =
+    {
+        let container_ref = container_ref.clone();
+        let scroll_top = scroll_top.clone();
+        let viewport_height = viewport_height.clone();
+        let content_offset = content_offset.clone();
+        let anchor = anchor.clone();
+        let at_bottom = at_bottom.clone();
+        let row_key = props.row_key.clone();
+        let row_count = props.row_count;
+        let row_height = props.row_height;
+
+        Callback::from(move |_: Event|
+        {
+            if let Some(container) = container_ref.cast::<web_sys::Element>()
+            {
+                let top = container.scroll_top() as f64;
+                let rows_top = (top - *content_offset).max(0.0);
+                let index = (rows_top / row_height) as usize;
+
+                *anchor.borrow_mut() = (index < row_count)
+                    .then(|| (row_key.emit(index), rows_top - index as f64 * row_height));
+                *at_bottom.borrow_mut() = container.scroll_height() - container.scroll_top() - container.client_height() <= FOLLOW_THRESHOLD_PX;
+
+                scroll_top.set(top);
+                viewport_height.set(container.client_height() as f64);
+            }
+        })
+    };
+
+    let rows_top = (*scroll_top - *content_offset).max(0.0);
+    let first_visible = (rows_top / props.row_height) as usize;
+    let last_visible = ((rows_top + *viewport_height) / props.row_height).ceil() as usize;
+    let start = first_visible.saturating_sub(OVERSCAN_ROWS).min(props.row_count);
+    let end = (last_visible + OVERSCAN_ROWS).min(props.row_count);
+
+    html!
+    {
+        <div class={classes!("virtual-list", props.class.clone())} ref={container_ref} onscroll={on_scroll}>
+            <div style={format!("position: relative; height: {}px;", props.row_count as f64 * props.row_height)}>
+                <div style={format!("position: absolute; top: {}px; left: 0; min-width: 100%;", start as f64 * props.row_height)}>
+                    { for (start..end).map(|i| props.render_row.emit(i)) }
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Distance from the top of the scrollable content to the rows, which is the container's top padding.
+fn measure_content_offset(container: &web_sys::Element) -> f64
+{
+    container.first_element_child().map_or(0.0, |rows|
+    {
+        rows.get_bounding_client_rect().top() - container.get_bounding_client_rect().top()
+            - container.client_top() as f64
+            + container.scroll_top() as f64
+    })
+}
+
+/// Index of the first row for which `is_before` is false, `is_before` being monotonic.
+fn partition_point(len: usize, is_before: impl Fn(usize) -> bool) -> usize
+{
+    let (mut low, mut high) = (0, len);
+    while low < high
+    {
+        let mid = low + (high - low) / 2;
+        if is_before(mid)
+        {
+            low = mid + 1;
+        }
+        else
+        {
+            high = mid;
+        }
+    }
+    low
+}
\ No newline at end of file
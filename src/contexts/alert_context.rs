@@ -0,0 +1,161 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use i18nrs::I18n;
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+use crate::contexts::sse_context::use_sse_metrics_history;
+use crate::models::alert::{AlertMetric, AlertRule, FiringAlert};
+use crate::services::alert_service;
+
+#[derive(Clone, PartialEq)]
+pub struct AlertContext
+{
+    pub rules: Vec<AlertRule>,
+    pub firing: Vec<FiringAlert>,
+    /// Replaces the rules and persists them to localStorage.
+    pub on_rules_change: Callback<Vec<AlertRule>>,
+    /// Hides the banner of a rule until it resolves and fires again.
+    pub on_dismiss: Callback<u32>,
+}
+
+pub fn describe_rule(rule: &AlertRule, i18n: &I18n) -> String
+{
+    let metric = match rule.metric
+    {
+        AlertMetric::Cpu => i18n.t("project_dashboard.alert_metric_cpu"),
+        AlertMetric::Memory => i18n.t("project_dashboard.alert_metric_memory"),
+    };
+
+    i18n.t("project_dashboard.alert_rule_description")
+        .replace("{metric}", &metric)
+        .replace("{threshold}", &format!("{:.0}", rule.threshold))
+        .replace("{duration}", &rule.duration_secs.to_string())
+}
+
+#[derive(Properties, PartialEq)]
+pub struct AlertProviderProps
+{
+    pub project_id: i32,
+    pub project_name: String,
+    pub children: Children,
+}
+
+/// Evaluates the project's alert rules against every metrics sample received over SSE.
+/// Must be rendered inside an `SseProvider`.
+#[function_component(AlertProvider)]
+pub fn alert_provider(props: &AlertProviderProps) -> Html
+{
+    let (i18n, _) = use_translation();
+    let history = use_sse_metrics_history();
+    let rules =
+    {
+        let project_id = props.project_id;
+        use_state(move || alert_service::load_rules(project_id))
+    };
+    let firing = use_state(Vec::<FiringAlert>::new);
+
+    // When each rule started being breached, by rule id
+    let breached_since = use_mut_ref(HashMap::<u32, f64>::new);
+    // Rules that already fired and have not resolved since, so they do not fire on every sample
+    let notified = use_mut_ref(HashSet::<u32>::new);
+
+    {
+        let rules = rules.clone();
+        let firing = firing.clone();
+        let project_name = props.project_name.clone();
+        let latest = history.points.back().cloned();
+
+        use_effect_with((latest, (*rules).clone()), move |(latest, _)|
+        {
+            if let Some(point) = latest
+            {
+                let mut breached_since = breached_since.borrow_mut();
+                let mut notified = notified.borrow_mut();
+                let mut next_firing = (*firing).clone();
+
+                for rule in rules.iter()
+                {
+                    let value = rule.metric.value(&point.metrics);
+
+                    let Some(value) = value.filter(|&v| v > rule.threshold)
+                    else
+                    {
+                        breached_since.remove(&rule.id);
+                        notified.remove(&rule.id);
+                        next_firing.retain(|a| a.rule.id != rule.id);
+                        continue;
+                    };
+
+                    let since = *breached_since.entry(rule.id).or_insert(point.at_ms);
+                    let held_long_enough = point.at_ms - since >= f64::from(rule.duration_secs) * 1000.0;
+
+                    if held_long_enough && notified.insert(rule.id)
+                    {
+                        let description = describe_rule(rule, &i18n);
+                        alert_service::notify(
+                            &i18n.t("project_dashboard.alert_notification_title").replace("{name}", &project_name),
+                            &description,
+                        );
+                        next_firing.push(FiringAlert { rule: rule.clone(), value });
+                    }
+                }
+
+                // Rules deleted while firing
+                next_firing.retain(|a| rules.iter().any(|r| r.id == a.rule.id));
+
+                if next_firing != *firing
+                {
+                    firing.set(next_firing);
+                }
+            }
+            || ()
+        });
+    }
+
+    let on_rules_change =
+    {
+        let rules = rules.clone();
+        let project_id = props.project_id;
+
+        Callback::from(move |next: Vec<AlertRule>|
+        {
+            alert_service::save_rules(project_id, &next);
+            rules.set(next);
+        })
+    };
+
+    let on_dismiss =
+    {
+        let firing = firing.clone();
+
+        Callback::from(move |rule_id: u32|
+        {
+            let mut next = (*firing).clone();
+            next.retain(|a| a.rule.id != rule_id);
+            firing.set(next);
+        })
+    };
+
+    let context = AlertContext
+    {
+        rules: (*rules).clone(),
+        firing: (*firing).clone(),
+        on_rules_change,
+        on_dismiss,
+    };
+
+    html!
+    {
+        <ContextProvider<Rc<AlertContext>> context={Rc::new(context)}>
+            { for props.children.iter() }
+        </ContextProvider<Rc<AlertContext>>>
+    }
+}
+
+#[hook]
+pub fn use_alerts() -> Rc<AlertContext>
+{
+    use_context::<Rc<AlertContext>>().expect("AlertContext not found")
+}
\ No newline at end of file
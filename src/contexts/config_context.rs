@@ -0,0 +1,60 @@
+use std::rc::Rc;
+use yew::prelude::*;
+
+use crate::models::config::AppConfig;
+use crate::services::{api_client, config_service};
+
+pub type ConfigContext = Rc<AppConfig>;
+
+#[derive(Properties, PartialEq)]
+pub struct ConfigProviderProps
+{
+    pub children: Children,
+}
+
+/// Loads `/config.json` before rendering its children, so that every service call
+/// made below it already targets the configured API root.
+#[function_component(ConfigProvider)]
+pub fn config_provider(props: &ConfigProviderProps) -> Html
+{
+    let config = use_state(|| None::<ConfigContext>);
+
+    {
+        let config = config.clone();
+        use_effect_with((), move |_|
+        {
+            wasm_bindgen_futures::spawn_local(async move
+            {
+                let loaded = match config_service::fetch_config().await
+                {
+                    Ok(c) => c,
+                    Err(e) =>
+                    {
+                        gloo_console::warn!("Failed to load /config.json, using defaults:", e.error_code);
+                        AppConfig::default()
+                    }
+                };
+                api_client::set_api_root(&loaded.api_root);
+                config.set(Some(Rc::new(loaded)));
+            });
+            || ()
+        });
+    }
+
+    match &*config
+    {
+        Some(config) => html!
+        {
+            <ContextProvider<ConfigContext> context={config.clone()}>
+                {props.children.clone()}
+            </ContextProvider<ConfigContext>>
+        },
+        None => html! {},
+    }
+}
+
+#[hook]
+pub fn use_config() -> ConfigContext
+{
+    use_context::<ConfigContext>().expect("use_config must be used within a ConfigProvider")
+}
\ No newline at end of file
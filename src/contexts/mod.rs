@@ -1,2 +1,4 @@
 pub mod user_context;
-pub mod sse_context;
\ No newline at end of file
+pub mod sse_context;
+pub mod config_context;
+pub mod alert_context;
\ No newline at end of file
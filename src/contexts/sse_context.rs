@@ -1,9 +1,26 @@
+use std::cell::{Ref, RefCell};
+use std::collections::VecDeque;
 use std::rc::Rc;
 use yew::prelude::*;
 
 use crate::hooks::use_sse::use_sse_project;
-use crate::models::project::ProjectMetrics;
-use crate::services::sse_service::{ContainerStatus, DeploymentStage, SseEvent, SystemEvent};
+use crate::models::project::{MetricsSample, ProjectMetrics};
+use crate::services::project_service;
+use crate::services::sse_service::{
+    BuildLogEvent, ContainerStatus, DeploymentEvent, DeploymentStage, LogEvent, LogStream, MetricsEvent, SseConnectionStatus, SseEvent, SystemEvent
+};
+use crate::utils::ansi::{parse_ansi, AnsiText};
+use crate::utils::log_parser::{timestamp_ms, ParsedLogLine};
+
+/// Upper bound on streamed log lines kept in memory, oldest lines are dropped first.
+pub const MAX_LOG_LINES: usize = 5000;
+
+/// Upper bound on build output lines kept for the current deployment.
+pub const MAX_BUILD_LOG_LINES: usize = 2000;
+
+/// Metrics samples older than this are dropped from the history.
+pub const METRICS_HISTORY_MINUTES: u32 = 60;
+const MAX_METRICS_SAMPLES: usize = 3600;
 
 #[derive(Clone, PartialEq)]
 pub struct SseStatusContext
@@ -20,7 +37,7 @@ pub struct SseMetricsContext
 #[derive(Clone, PartialEq)]
 pub struct SseDeploymentContext
 {
-    pub stage: Option<DeploymentStage>,
+    pub event: Option<DeploymentEvent>,
 }
 
 #[derive(Clone, PartialEq)]
@@ -29,6 +46,265 @@ pub struct SseSystemContext
     pub events: Vec<SystemEvent>,
 }
 
+#[derive(Clone, PartialEq)]
+pub struct SseConnectionContext
+{
+    pub status: SseConnectionStatus,
+    pub reconnect: Callback<()>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogLine
+{
+    /// Position of the line in the stream since the page was opened, never reused.
+    pub seq: u64,
+    pub stream: LogStream,
+    pub line: Rc<ParsedLogLine>,
+}
+
+/// Streamed log lines. The lines are shared between states and appended in place, so an
+/// event costs its own lines rather than a copy of the whole buffer; `next_seq` tells states apart.
+#[derive(Clone, Default)]
+pub struct SseLogBuffer
+{
+    lines: Rc<RefCell<VecDeque<LogLine>>>,
+    pub next_seq: u64,
+}
+
+impl SseLogBuffer
+{
+    /// The last `MAX_LOG_LINES` lines, oldest first.
+    pub fn lines(&self) -> Ref<'_, VecDeque<LogLine>>
+    {
+        self.lines.borrow()
+    }
+}
+
+impl PartialEq for SseLogBuffer
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        Rc::ptr_eq(&self.lines, &other.lines) && self.next_seq == other.next_seq
+    }
+}
+
+impl Reducible for SseLogBuffer
+{
+    type Action = LogEvent;
+
+    fn reduce(self: Rc<Self>, event: Self::Action) -> Rc<Self>
+    {
+        let mut next_seq = self.next_seq;
+        {
+            let mut lines = self.lines.borrow_mut();
+
+            for text in event.lines
+            {
+                lines.push_back(LogLine
+                {
+                    seq: next_seq,
+                    stream: event.stream,
+                    line: Rc::new(ParsedLogLine::parse(&text)),
+                });
+                next_seq += 1;
+            }
+
+            while lines.len() > MAX_LOG_LINES
+            {
+                lines.pop_front();
+            }
+        }
+
+        SseLogBuffer
+        {
+            lines: self.lines.clone(),
+            next_seq,
+        }
+        .into()
+    }
+}
+
+pub type SseLogContext = UseReducerHandle<SseLogBuffer>;
+
+/// Build output of the current deployment, kept after it fails so the error can be read.
+#[derive(Clone, PartialEq, Default)]
+pub struct BuildLogBuffer
+{
+    pub lines: VecDeque<Rc<AnsiText>>,
+    /// Lines dropped from the front to stay under `MAX_BUILD_LOG_LINES`
+    pub dropped: usize,
+}
+
+pub enum BuildLogAction
+{
+    Append(BuildLogEvent),
+    /// A new deployment started.
+    Clear,
+}
+
+impl Reducible for BuildLogBuffer
+{
+    type Action = BuildLogAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self>
+    {
+        let event = match action
+        {
+            BuildLogAction::Append(event) => event,
+            BuildLogAction::Clear => return Self::default().into(),
+        };
+
+        let mut next = (*self).clone();
+        next.lines.extend(event.lines.iter().map(|line| Rc::new(parse_ansi(line))));
+
+        while next.lines.len() > MAX_BUILD_LOG_LINES
+        {
+            next.lines.pop_front();
+            next.dropped += 1;
+        }
+
+        next.into()
+    }
+}
+
+pub type BuildLogContext = UseReducerHandle<BuildLogBuffer>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimelineEntry
+{
+    pub stage: DeploymentStage,
+    /// When the backend entered the stage, in milliseconds since the epoch
+    pub at_ms: f64,
+    /// When the event reached the browser, the live timer counts from here to ignore clock skew
+    pub received_ms: f64,
+}
+
+/// Every stage seen since the deployment started, in order, recorded as the events arrive.
+#[derive(Clone, PartialEq, Default)]
+pub struct DeploymentTimeline
+{
+    pub entries: Vec<TimelineEntry>,
+}
+
+impl DeploymentTimeline
+{
+    pub fn stage(&self) -> Option<&DeploymentStage>
+    {
+        self.entries.last().map(|e| &e.stage)
+    }
+}
+
+pub enum DeploymentTimelineAction
+{
+    Record(DeploymentEvent),
+    /// Forgets the previous deployment before a new one is requested.
+    Clear,
+}
+
+impl Reducible for DeploymentTimeline
+{
+    type Action = DeploymentTimelineAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self>
+    {
+        let event = match action
+        {
+            DeploymentTimelineAction::Record(event) => event,
+            DeploymentTimelineAction::Clear => return Self::default().into(),
+        };
+
+        if self.stage() == Some(&event.stage)
+        {
+            return self;
+        }
+
+        let received_ms = js_sys::Date::now();
+        let at_ms = timestamp_ms(&event.timestamp).unwrap_or(received_ms);
+
+        // A new deployment starts a new timeline
+        let mut next = if event.stage == DeploymentStage::Started { Self::default() } else { (*self).clone() };
+        next.entries.push(TimelineEntry { stage: event.stage, at_ms, received_ms });
+        next.into()
+    }
+}
+
+pub type DeploymentTimelineContext = UseReducerHandle<DeploymentTimeline>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricsPoint
+{
+    /// Milliseconds since the epoch
+    pub at_ms: f64,
+    pub metrics: ProjectMetrics,
+}
+
+/// Ring buffer of the last `METRICS_HISTORY_MINUTES` of samples, oldest first.
+#[derive(Clone, PartialEq, Default)]
+pub struct MetricsHistory
+{
+    pub points: VecDeque<MetricsPoint>,
+}
+
+pub enum MetricsHistoryAction
+{
+    Push(MetricsSample),
+    /// Samples loaded from the backend, merged in front of the ones already streamed.
+    Prefill(Vec<MetricsSample>),
+}
+
+impl Reducible for MetricsHistory
+{
+    type Action = MetricsHistoryAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self>
+    {
+        let mut next = (*self).clone();
+
+        let to_point = |sample: MetricsSample| timestamp_ms(&sample.timestamp).map(|at_ms| MetricsPoint
+        {
+            at_ms,
+            metrics: sample.metrics,
+        });
+
+        match action
+        {
+            MetricsHistoryAction::Push(sample) =>
+            {
+                if let Some(point) = to_point(sample)
+                    && next.points.back().is_none_or(|last| point.at_ms > last.at_ms)
+                {
+                    next.points.push_back(point);
+                }
+            }
+            MetricsHistoryAction::Prefill(samples) =>
+            {
+                let first_streamed = next.points.front().map(|p| p.at_ms);
+                let older = samples
+                    .into_iter()
+                    .filter_map(to_point)
+                    .filter(|p| first_streamed.is_none_or(|first| p.at_ms < first))
+                    .collect::<Vec<_>>();
+
+                for point in older.into_iter().rev()
+                {
+                    next.points.push_front(point);
+                }
+            }
+        }
+
+        let oldest_kept = js_sys::Date::now() - f64::from(METRICS_HISTORY_MINUTES) * 60_000.0;
+        while next.points.len() > MAX_METRICS_SAMPLES
+            || next.points.front().is_some_and(|p| p.at_ms < oldest_kept)
+        {
+            next.points.pop_front();
+        }
+
+        next.into()
+    }
+}
+
+pub type SseMetricsHistoryContext = UseReducerHandle<MetricsHistory>;
+
 #[derive(Properties, PartialEq)]
 pub struct SseProviderProps
 {
@@ -39,50 +315,121 @@ pub struct SseProviderProps
 #[function_component(SseProvider)]
 pub fn sse_provider(props: &SseProviderProps) -> Html
 {
-    let sse_state = use_sse_project(props.project_id);
-    
-    let current_status = use_state(|| None::<ContainerStatus>);
-    let current_metrics = use_state(|| None::<ProjectMetrics>);
-    let deployment_stage = use_state(|| None::<DeploymentStage>);
+    let log_buffer = use_reducer(SseLogBuffer::default);
+    let on_log =
+    {
+        let log_buffer = log_buffer.clone();
+        Callback::from(move |event: LogEvent| log_buffer.dispatch(event))
+    };
+
+    let build_log = use_reducer(BuildLogBuffer::default);
+    let on_build_log =
+    {
+        let build_log = build_log.clone();
+        Callback::from(move |event: BuildLogEvent| build_log.dispatch(BuildLogAction::Append(event)))
+    };
+
+    let deployment_timeline = use_reducer(DeploymentTimeline::default);
+    let on_deployment =
+    {
+        let deployment_timeline = deployment_timeline.clone();
+        let build_log = build_log.clone();
+        Callback::from(move |event: DeploymentEvent|
+        {
+            // Cleared as the event arrives: a `Started` quickly followed by the next stage
+            // may never be the latest event at render time
+            if event.stage == DeploymentStage::Started
+            {
+                build_log.dispatch(BuildLogAction::Clear);
+            }
+            deployment_timeline.dispatch(DeploymentTimelineAction::Record(event));
+        })
+    };
+
+    let sse_state = use_sse_project(props.project_id, on_log, on_build_log, on_deployment);
     let system_events = use_state(Vec::<SystemEvent>::new);
+    let metrics_history = use_reducer(MetricsHistory::default);
+
+    {
+        let metrics_history = metrics_history.clone();
+
+        use_effect_with(props.project_id, move |&project_id|
+        {
+            wasm_bindgen_futures::spawn_local(async move
+            {
+                match project_service::get_project_metrics_history(project_id, METRICS_HISTORY_MINUTES).await
+                {
+                    Ok(samples) => metrics_history.dispatch(MetricsHistoryAction::Prefill(samples)),
+                    Err(e) => gloo_console::warn!("Metrics history unavailable:", e.error_code),
+                }
+            });
+            || ()
+        });
+    }
+
+    // The hook keeps only the latest event of each kind
+    let mut current_status = None::<ContainerStatus>;
+    let mut latest_metrics_event = None::<MetricsEvent>;
+    let mut deployment_event = None::<DeploymentEvent>;
+    let mut latest_system_event = None::<SystemEvent>;
+
+    for event in sse_state.events.iter()
+    {
+        match event
+        {
+            SseEvent::ContainerStatus(status_event) =>
+            {
+                current_status = Some(status_event.status.clone());
+            }
+            SseEvent::Metrics(metrics_event) =>
+            {
+                latest_metrics_event = Some(metrics_event.clone());
+            }
+            SseEvent::Deployment(deploy_event) =>
+            {
+                deployment_event = Some(deploy_event.clone());
+            }
+            SseEvent::System(system_event) =>
+            {
+                latest_system_event = Some(system_event.clone());
+            }
+            SseEvent::Log(_) | SseEvent::BuildLog(_) => {}
+        }
+    }
 
     {
-        let current_status = current_status.clone();
-        let current_metrics = current_metrics.clone();
-        let deployment_stage = deployment_stage.clone();
         let system_events = system_events.clone();
 
-        use_effect_with(sse_state.events.clone(), move |events|
+        use_effect_with(latest_system_event, move |latest|
         {
-            for event in events.iter()
+            if let Some(system_event) = latest
             {
-                match event
+                let mut events = (*system_events).clone();
+                events.push(system_event.clone());
+
+                if events.len() > 5
                 {
-                    SseEvent::ContainerStatus(status_event) =>
-                    {
-                        current_status.set(Some(status_event.status.clone()));
-                    }
-                    SseEvent::Metrics(metrics_event) =>
-                    {
-                        current_metrics.set(Some(metrics_event.metrics.clone()));
-                    }
-                    SseEvent::Deployment(deploy_event) =>
-                    {
-                        deployment_stage.set(Some(deploy_event.stage.clone()));
-                    }
-                    SseEvent::System(system_event) => 
-                    { 
-                        let mut events = (*system_events).clone();
-                        events.push(system_event.clone());
-                        
-                        if events.len() > 5
-                        {
-                            events.remove(0);
-                        }
-                        
-                        system_events.set(events);
-                    }
+                    events.remove(0);
                 }
+
+                system_events.set(events);
+            }
+            || ()
+        });
+    }
+
+    {
+        let metrics_history = metrics_history.clone();
+
+        use_effect_with(latest_metrics_event.clone(), move |latest|
+        {
+            if let Some(metrics_event) = latest
+            {
+                metrics_history.dispatch(MetricsHistoryAction::Push(MetricsSample
+                {
+                    timestamp: metrics_event.timestamp.clone(),
+                    metrics: metrics_event.metrics.clone(),
+                }));
             }
             || ()
         });
@@ -90,17 +437,17 @@ pub fn sse_provider(props: &SseProviderProps) -> Html
 
     let status_context = SseStatusContext
     {
-        status: (*current_status).clone(),
+        status: current_status,
     };
 
     let metrics_context = SseMetricsContext
     {
-        metrics: (*current_metrics).clone(),
+        metrics: latest_metrics_event.map(|e| e.metrics),
     };
 
     let deployment_context = SseDeploymentContext
     {
-        stage: (*deployment_stage).clone(),
+        event: deployment_event,
     };
 
     let system_context = SseSystemContext
@@ -108,13 +455,29 @@ pub fn sse_provider(props: &SseProviderProps) -> Html
         events: (*system_events).clone(),
     };
 
+    let connection_context = SseConnectionContext
+    {
+        status: sse_state.status,
+        reconnect: sse_state.reconnect,
+    };
+
     html!
     {
         <ContextProvider<Rc<SseStatusContext>> context={Rc::new(status_context)}>
             <ContextProvider<Rc<SseMetricsContext>> context={Rc::new(metrics_context)}>
                 <ContextProvider<Rc<SseDeploymentContext>> context={Rc::new(deployment_context)}>
                     <ContextProvider<Rc<SseSystemContext>> context={Rc::new(system_context)}>
-                        { for props.children.iter() }
+                        <ContextProvider<Rc<SseConnectionContext>> context={Rc::new(connection_context)}>
+                            <ContextProvider<SseLogContext> context={log_buffer}>
+                                <ContextProvider<SseMetricsHistoryContext> context={metrics_history}>
+                                    <ContextProvider<BuildLogContext> context={build_log}>
+                                        <ContextProvider<DeploymentTimelineContext> context={deployment_timeline}>
+                                            { for props.children.iter() }
+                                        </ContextProvider<DeploymentTimelineContext>>
+                                    </ContextProvider<BuildLogContext>>
+                                </ContextProvider<SseMetricsHistoryContext>>
+                            </ContextProvider<SseLogContext>>
+                        </ContextProvider<Rc<SseConnectionContext>>>
                     </ContextProvider<Rc<SseSystemContext>>>
                 </ContextProvider<Rc<SseDeploymentContext>>>
             </ContextProvider<Rc<SseMetricsContext>>>
@@ -142,10 +505,16 @@ pub fn use_sse_metrics() -> Option<ProjectMetrics>
 
 #[hook]
 pub fn use_sse_deployment() -> Option<DeploymentStage>
+{
+    use_sse_deployment_event().map(|e| e.stage)
+}
+
+#[hook]
+pub fn use_sse_deployment_event() -> Option<DeploymentEvent>
 {
     use_context::<Rc<SseDeploymentContext>>()
         .expect("SseDeploymentContext not found")
-        .stage
+        .event
         .clone()
 }
 
@@ -156,4 +525,45 @@ pub fn use_sse_system_events() -> Vec<SystemEvent>
         .expect("SseSystemContext not found")
         .events
         .clone()
+}
+
+#[hook]
+pub fn use_sse_connection() -> SseConnectionStatus
+{
+    use_context::<Rc<SseConnectionContext>>()
+        .expect("SseConnectionContext not found")
+        .status
+}
+
+#[hook]
+pub fn use_sse_reconnect() -> Callback<()>
+{
+    use_context::<Rc<SseConnectionContext>>()
+        .expect("SseConnectionContext not found")
+        .reconnect
+        .clone()
+}
+
+#[hook]
+pub fn use_sse_logs() -> SseLogContext
+{
+    use_context::<SseLogContext>().expect("SseLogContext not found")
+}
+
+#[hook]
+pub fn use_sse_build_log() -> BuildLogContext
+{
+    use_context::<BuildLogContext>().expect("BuildLogContext not found")
+}
+
+#[hook]
+pub fn use_sse_deployment_timeline() -> DeploymentTimelineContext
+{
+    use_context::<DeploymentTimelineContext>().expect("DeploymentTimelineContext not found")
+}
+
+#[hook]
+pub fn use_sse_metrics_history() -> SseMetricsHistoryContext
+{
+    use_context::<SseMetricsHistoryContext>().expect("SseMetricsHistoryContext not found")
 }
\ No newline at end of file
@@ -1,7 +1,9 @@
-use gloo_net::eventsource::futures::EventSource;
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use yew::prelude::*;
 
-use crate::services::sse_service::{SseEvent, connect_to_creation, connect_to_project};
+use crate::services::sse_service::{BuildLogEvent, DeploymentEvent, LogEvent, SseConnection, SseConnectionStatus, SseEvent, connect_to_creation, connect_to_project};
 
 const MAX_EVENTS: usize = 100;
 
@@ -10,155 +12,187 @@ const MAX_EVENTS: usize = 100;
 pub struct SseState
 {
     pub events: Vec<SseEvent>,
-    pub connected: bool,
-    pub error: Option<String>,
+    pub status: SseConnectionStatus,
+    /// Reopens the connection, for the user to retry once it has `Failed`.
+    pub reconnect: Callback<()>,
 }
 
+pub enum SseAction
+{
+    /// Appends the event, keeping at most `MAX_EVENTS`.
+    Push(SseEvent),
+    /// Replaces the previous event of the same kind, keeping only the latest one of each.
+    ReplaceLatest(SseEvent),
+    Status(SseConnectionStatus),
+}
 
-#[hook]
-pub fn use_sse_creation() -> SseState
+impl Reducible for SseState
 {
-    let state = use_state(SseState::default);
-    let event_source = use_mut_ref(|| None::<EventSource>);
+    type Action = SseAction;
 
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self>
     {
-        let state = state.clone();
-        let event_source = event_source.clone();
+        let mut next = (*self).clone();
 
-        use_effect_with((), move |_| 
+        match action
         {
-            let state_clone = state.clone();
-
-            let on_message = Callback::from(move |event: SseEvent| 
+            SseAction::Push(event) =>
             {
-                let mut current = (*state_clone).clone();
-                current.events.push(event);
+                next.events.push(event);
 
-                if current.events.len() > MAX_EVENTS
+                if next.events.len() > MAX_EVENTS
                 {
-                    current.events.drain(0..(current.events.len() - MAX_EVENTS));
+                    next.events.drain(0..(next.events.len() - MAX_EVENTS));
                 }
+            }
+            SseAction::ReplaceLatest(event) =>
+            {
+                if let Some(pos) = next
+                    .events
+                    .iter()
+                    .position(|e| std::mem::discriminant(e) == std::mem::discriminant(&event))
+                {
+                    next.events[pos] = event;
+                }
+                else
+                {
+                    next.events.push(event);
+                }
+            }
+            SseAction::Status(status) =>
+            {
+                next.status = status;
+            }
+        }
 
-                current.connected = true;
-                current.error = None;
-                state_clone.set(current);
-            });
+        next.into()
+    }
+}
 
-            let state_error = state.clone();
-            let on_error = Callback::from(move |error: String| 
-            {
-                let mut current = (*state_error).clone();
-                current.connected = false;
-                current.error = Some(error);
-                state_error.set(current);
-            });
+/// Build output is handed to `on_build_log` line batch by line batch, like project logs.
+/// Deployment events are also handed to `on_deployment` as they arrive, none is skipped
+/// even when several land before the next render.
+#[hook]
+pub fn use_sse_creation(on_build_log: Callback<BuildLogEvent>, on_deployment: Callback<DeploymentEvent>) -> SseState
+{
+    let state = use_reducer(SseState::default);
+    let connection = use_mut_ref(|| None::<SseConnection>);
+    let reconnect = use_reconnect(connection.clone());
+
+    {
+        let state = state.clone();
+        let connection = connection.clone();
 
-            match connect_to_creation(on_message, on_error)
+        use_effect_with((), move |_|
+        {
+            let state_message = state.clone();
+            let on_message = Callback::from(move |event: SseEvent|
             {
-                Ok(es) =>
+                match event
                 {
-                    *event_source.borrow_mut() = Some(es);
-                    let mut current = (*state).clone();
-                    current.connected = true;
-                    current.error = None;
-                    state.set(current);
+                    SseEvent::BuildLog(build_log_event) => on_build_log.emit(build_log_event),
+                    SseEvent::Deployment(deployment_event) =>
+                    {
+                        on_deployment.emit(deployment_event.clone());
+                        state_message.dispatch(SseAction::Push(SseEvent::Deployment(deployment_event)));
+                    }
+                    event => state_message.dispatch(SseAction::Push(event)),
                 }
-                Err(e) =>
+            });
+
+            let on_status = Callback::from(move |status: SseConnectionStatus|
+            {
+                if status == SseConnectionStatus::Failed
                 {
-                    gloo_console::error!("Failed to connect to creation SSE:", &e);
-                    let mut current = (*state).clone();
-                    current.error = Some(e);
-                    current.connected = false;
-                    state.set(current);
+                    gloo_console::error!("Creation SSE connection failed");
                 }
-            }
+                state.dispatch(SseAction::Status(status));
+            });
 
-            move || 
+            *connection.borrow_mut() = Some(connect_to_creation(on_message, on_status));
+
+            move ||
             {
-                if let Some(es) = event_source.borrow_mut().take()
+                if let Some(c) = connection.borrow_mut().take()
                 {
-                    es.close();
+                    c.close();
                 }
             }
         });
     }
 
-    (*state).clone()
+    SseState { reconnect, ..(*state).clone() }
 }
 
+/// Log and build log events are streams rather than state: they are handed to `on_log`
+/// and `on_build_log` one by one instead of being kept in `SseState::events`.
+/// Deployment events are kept as the latest one and also handed to `on_deployment`.
 #[hook]
-pub fn use_sse_project(project_id: i32) -> SseState
+pub fn use_sse_project(
+    project_id: i32,
+    on_log: Callback<LogEvent>,
+    on_build_log: Callback<BuildLogEvent>,
+    on_deployment: Callback<DeploymentEvent>,
+) -> SseState
 {
-    let state = use_state(SseState::default);
-    let event_source = use_mut_ref(|| None::<EventSource>);
+    let state = use_reducer(SseState::default);
+    let connection = use_mut_ref(|| None::<SseConnection>);
+    let reconnect = use_reconnect(connection.clone());
 
     {
         let state = state.clone();
-        let event_source = event_source.clone();
+        let connection = connection.clone();
 
-        use_effect_with(project_id, move |&project_id| 
+        use_effect_with(project_id, move |&project_id|
         {
-            let state_clone = state.clone();
-
-            let on_message = Callback::from(move |event: SseEvent| 
+            let state_message = state.clone();
+            let on_message = Callback::from(move |event: SseEvent|
             {
-                let mut current = (*state_clone).clone();
-
-                if let Some(pos) = current
-                    .events
-                    .iter()
-                    .position(|e| std::mem::discriminant(e) == std::mem::discriminant(&event))
-                {
-                    current.events[pos] = event;
-                }
-                else
+                match event
                 {
-                    current.events.push(event);
+                    SseEvent::Log(log_event) => on_log.emit(log_event),
+                    SseEvent::BuildLog(build_log_event) => on_build_log.emit(build_log_event),
+                    SseEvent::Deployment(deployment_event) =>
+                    {
+                        on_deployment.emit(deployment_event.clone());
+                        state_message.dispatch(SseAction::ReplaceLatest(SseEvent::Deployment(deployment_event)));
+                    }
+                    event => state_message.dispatch(SseAction::ReplaceLatest(event)),
                 }
-
-                current.connected = true;
-                current.error = None;
-                state_clone.set(current);
-            });
-
-            let state_error = state.clone();
-            let on_error = Callback::from(move |error: String| 
-            {
-                let mut current = (*state_error).clone();
-                current.connected = false;
-                current.error = Some(error);
-                state_error.set(current);
             });
 
-            match connect_to_project(project_id, on_message, on_error)
+            let on_status = Callback::from(move |status: SseConnectionStatus|
             {
-                Ok(es) =>
+                if status == SseConnectionStatus::Failed
                 {
-                    *event_source.borrow_mut() = Some(es);
-                    let mut current = (*state).clone();
-                    current.connected = true;
-                    current.error = None;
-                    state.set(current);
+                    gloo_console::error!("Project SSE connection failed");
                 }
-                Err(e) =>
-                {
-                    gloo_console::error!("Failed to connect to SSE:", &e);
-                    let mut current = (*state).clone();
-                    current.error = Some(e);
-                    current.connected = false;
-                    state.set(current);
-                }
-            }
+                state.dispatch(SseAction::Status(status));
+            });
+
+            *connection.borrow_mut() = Some(connect_to_project(project_id, on_message, on_status));
 
-            move || 
+            move ||
             {
-                if let Some(es) = event_source.borrow_mut().take()
+                if let Some(c) = connection.borrow_mut().take()
                 {
-                    es.close();
+                    c.close();
                 }
             }
         });
     }
 
-    (*state).clone()
+    SseState { reconnect, ..(*state).clone() }
 }
+
+#[hook]
+fn use_reconnect(connection: Rc<RefCell<Option<SseConnection>>>) -> Callback<()>
+{
+    use_callback((), move |_, _|
+    {
+        if let Some(c) = connection.borrow().as_ref()
+        {
+            c.reconnect();
+        }
+    })
+}
\ No newline at end of file
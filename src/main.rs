@@ -6,6 +6,7 @@ mod pages;
 mod router;
 mod services;
 mod hooks;
+mod utils;
 
 use app::App;
 
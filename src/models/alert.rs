@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::project::ProjectMetrics;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertMetric
+{
+    /// CPU usage, in percent of one core
+    Cpu,
+    /// Memory usage, in percent of the container limit
+    Memory,
+}
+
+impl AlertMetric
+{
+    pub fn value(self, metrics: &ProjectMetrics) -> Option<f64>
+    {
+        match self
+        {
+            AlertMetric::Cpu => Some(metrics.cpu_usage),
+            AlertMetric::Memory => (metrics.memory_limit > 0.0)
+                .then(|| metrics.memory_usage / metrics.memory_limit * 100.0),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AlertRule
+{
+    pub id: u32,
+    pub metric: AlertMetric,
+    /// Percentage above which the rule is breached
+    pub threshold: f64,
+    /// How long the threshold must stay exceeded before the alert fires, `0` to fire on the first sample
+    pub duration_secs: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FiringAlert
+{
+    pub rule: AlertRule,
+    /// Value of the sample that made the rule fire
+    pub value: f64,
+}
\ No newline at end of file
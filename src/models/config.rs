@@ -0,0 +1,50 @@
+use serde::Deserialize;
+
+/// Deployment-specific settings loaded from `/config.json` at startup.
+/// Every field is optional in the file and falls back to the production Hangar values.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct AppConfig
+{
+    pub api_root: String,
+    pub project_url_template: String,
+    pub phpmyadmin_url: String,
+    pub cas_login_url: String,
+    pub github_app_name: String,
+    /// Starter repository of a project template, `{name}` being the template's repository name
+    pub template_repo_url_template: String,
+}
+
+impl Default for AppConfig
+{
+    fn default() -> Self
+    {
+        AppConfig
+        {
+            api_root: "/api".to_string(),
+            project_url_template: "https://{name}.hangar.garageisep.com".to_string(),
+            phpmyadmin_url: "https://phpmyadmin.hangar.garageisep.com".to_string(),
+            cas_login_url: "https://portail-ovh.isep.fr/cas/login".to_string(),
+            github_app_name: "hangar-app".to_string(),
+            template_repo_url_template: "https://github.com/GarageISEP/{name}".to_string(),
+        }
+    }
+}
+
+impl AppConfig
+{
+    pub fn project_url(&self, project_name: &str) -> String
+    {
+        self.project_url_template.replace("{name}", project_name)
+    }
+
+    pub fn template_repo_url(&self, repo_name: &str) -> String
+    {
+        self.template_repo_url_template.replace("{name}", repo_name)
+    }
+
+    pub fn github_app_install_url(&self) -> String
+    {
+        format!("https://github.com/apps/{}/installations/new", self.github_app_name)
+    }
+}
\ No newline at end of file
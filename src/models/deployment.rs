@@ -0,0 +1,73 @@
+use serde::Deserialize;
+
+use crate::services::sse_service::DeploymentStage;
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentTrigger
+{
+    Create,
+    ImageUpdate,
+    Rebuild,
+    EnvChange,
+    Rollback,
+    /// Started by a push webhook on the deployed branch
+    Push,
+    /// A trigger added by a newer backend
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Deployment
+{
+    pub id: i32,
+    pub project_id: i32,
+    pub trigger: DeploymentTrigger,
+    /// Login of the user who started the deployment
+    pub triggered_by: String,
+    /// `None` until the image has been pulled or built
+    pub image_digest: Option<String>,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    /// `None` while the deployment is still running
+    pub final_stage: Option<DeploymentStage>,
+}
+
+#[derive(Deserialize)]
+pub struct DeploymentsResponse
+{
+    pub deployments: Vec<Deployment>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus
+{
+    /// The push started a deployment
+    Deployed,
+    /// The push was not on the deployed branch, or auto-deploy was off
+    Ignored,
+    /// The payload was rejected, e.g. an invalid signature
+    Rejected,
+}
+
+/// A GitHub push webhook received for the project.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct WebhookDelivery
+{
+    pub delivered_at: String,
+    pub branch: String,
+    pub commit_sha: String,
+    pub commit_message: Option<String>,
+    pub pusher: Option<String>,
+    pub status: WebhookDeliveryStatus,
+    /// Deployment started by the push, when `status` is `Deployed`
+    pub deployment: Option<Deployment>,
+}
+
+#[derive(Deserialize)]
+pub struct WebhookDeliveryResponse
+{
+    pub delivery: Option<WebhookDelivery>,
+}
\ No newline at end of file
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum DeployMethod
+{
+    #[default]
+    GitHub,
+    Direct,
+    Database,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum WizardStep
+{
+    #[default]
+    Source,
+    Configuration,
+    Collaborators,
+    Review,
+}
+
+impl WizardStep
+{
+    pub const ALL: [WizardStep; 4] = [
+        WizardStep::Source,
+        WizardStep::Configuration,
+        WizardStep::Collaborators,
+        WizardStep::Review,
+    ];
+
+    pub fn index(self) -> usize
+    {
+        WizardStep::ALL.iter().position(|s| *s == self).unwrap_or(0)
+    }
+
+    pub fn next(self) -> Option<WizardStep>
+    {
+        WizardStep::ALL.get(self.index() + 1).copied()
+    }
+
+    pub fn previous(self) -> Option<WizardStep>
+    {
+        self.index().checked_sub(1).map(|i| WizardStep::ALL[i])
+    }
+
+    pub fn translation_key(self) -> &'static str
+    {
+        match self
+        {
+            WizardStep::Source => "create_project.step_source",
+            WizardStep::Configuration => "create_project.step_configuration",
+            WizardStep::Collaborators => "create_project.step_collaborators",
+            WizardStep::Review => "create_project.step_review",
+        }
+    }
+}
+
+/// What has been typed in `CreateProject`, kept across reloads and the GitHub App round trip.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CreateProjectDraft
+{
+    pub method: DeployMethod,
+    pub step: WizardStep,
+    pub project_name: String,
+    pub github_repo_url: String,
+    pub github_branch: String,
+    pub github_root_dir: String,
+    pub image_url: String,
+    /// `KEY=VALUE` lines, as in the textarea
+    pub env_vars: String,
+    /// Env var keys whose values were not saved and must be entered again
+    pub missing_env_values: Vec<String>,
+    pub volume_path: String,
+    pub create_database: bool,
+    /// Comma-separated logins, as in the input
+    pub participants: String,
+    /// Milliseconds since the epoch
+    pub saved_at: f64,
+}
+
+impl CreateProjectDraft
+{
+    /// True while nothing worth restoring has been typed.
+    pub fn is_empty(&self) -> bool
+    {
+        let blank = CreateProjectDraft
+        {
+            method: self.method,
+            step: self.step,
+            saved_at: self.saved_at,
+            ..Default::default()
+        };
+        *self == blank
+    }
+
+    /// Env var values can be secrets; only their keys are written to storage, and those that
+    /// had a value are listed in `missing_env_values`.
+    pub fn without_secrets(mut self) -> Self
+    {
+        let keys: Vec<&str> = self.env_vars.lines().filter_map(|l| l.split_once('=')).map(|(k, _)| k.trim()).collect();
+        self.missing_env_values.retain(|k| keys.contains(&k.as_str()));
+
+        for line in self.env_vars.lines()
+        {
+            if let Some((key, value)) = line.split_once('=')
+                && !value.trim().is_empty()
+                && !self.missing_env_values.iter().any(|k| k == key.trim())
+            {
+                self.missing_env_values.push(key.trim().to_string());
+            }
+        }
+
+        self.env_vars = self
+            .env_vars
+            .lines()
+            .map(|line| match line.split_once('=')
+            {
+                Some((key, _)) => format!("{}=", key),
+                None => line.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        self
+    }
+}
\ No newline at end of file
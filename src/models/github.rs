@@ -0,0 +1,31 @@
+use serde::Deserialize;
+
+/// A repository the installed GitHub App can read.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct GithubRepository
+{
+    /// `owner/name`
+    pub full_name: String,
+    /// What `DeployPayload::github_repo_url` expects
+    pub html_url: String,
+    pub default_branch: String,
+    pub private: bool,
+}
+
+#[derive(Deserialize)]
+pub struct GithubRepositoriesResponse
+{
+    pub repositories: Vec<GithubRepository>,
+}
+
+#[derive(Deserialize)]
+pub struct GithubBranchesResponse
+{
+    pub branches: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct GithubDirectoriesResponse
+{
+    pub directories: Vec<String>,
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::project::{DeployPayload, ProjectDetails, ProjectSourceType};
+
+/// Bumped whenever a field changes meaning; newer manifests are refused rather than half-read.
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// Where the project is built or pulled from.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ManifestSource
+{
+    Github
+    {
+        repository: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        branch: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        root_dir: Option<String>,
+    },
+    Direct
+    {
+        image: String,
+    },
+}
+
+/// Declarative project config, stored as `hangar.json` or `hangar.toml` next to the Dockerfile.
+/// Only env var keys are kept: values are secrets and must be filled in on import.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProjectManifest
+{
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub source: ManifestSource,
+    #[serde(default)]
+    pub participants: Vec<String>,
+    #[serde(default)]
+    pub env: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume_path: Option<String>,
+    #[serde(default)]
+    pub database: bool,
+}
+
+impl ProjectManifest
+{
+    pub fn from_details(details: &ProjectDetails) -> Self
+    {
+        let p = &details.project;
+
+        let source = match p.source
+        {
+            ProjectSourceType::Github => ManifestSource::Github
+            {
+                repository: p.source_url.clone(),
+                branch: p.source_branch.clone(),
+                root_dir: p.source_root_dir.clone(),
+            },
+            ProjectSourceType::Direct => ManifestSource::Direct
+            {
+                image: p.source_url.clone(),
+            },
+        };
+
+        let mut env: Vec<String> = p
+            .env_vars
+            .as_ref()
+            .map(|vars| vars.keys().cloned().collect())
+            .unwrap_or_default();
+        env.sort();
+
+        ProjectManifest
+        {
+            version: MANIFEST_VERSION,
+            name: Some(p.name.clone()),
+            source,
+            participants: details.participants.clone(),
+            env,
+            volume_path: p.persistent_volume_path.clone(),
+            database: details.database.is_some(),
+        }
+    }
+
+    /// Env vars come back with empty values, which `CreateProject` requires the user to fill in.
+    pub fn into_payload(self) -> DeployPayload
+    {
+        let mut payload = DeployPayload
+        {
+            project_name: self.name.unwrap_or_default(),
+            participants: self.participants,
+            env_vars: if self.env.is_empty()
+            {
+                None
+            }
+            else
+            {
+                Some(self.env.into_iter().map(|key| (key, String::new())).collect::<HashMap<_, _>>())
+            },
+            persistent_volume_path: self.volume_path,
+            create_database: self.database.then_some(true),
+            ..Default::default()
+        };
+
+        match self.source
+        {
+            ManifestSource::Github { repository, branch, root_dir } =>
+            {
+                payload.github_repo_url = Some(repository);
+                payload.github_branch = branch;
+                payload.github_root_dir = root_dir;
+            }
+            ManifestSource::Direct { image } =>
+            {
+                payload.image_url = Some(image);
+            }
+        }
+
+        payload
+    }
+}
\ No newline at end of file
@@ -1,3 +1,11 @@
 pub mod user;
 pub mod project;
-pub mod database;
\ No newline at end of file
+pub mod database;
+pub mod config;
+pub mod alert;
+pub mod deployment;
+pub mod scan;
+pub mod github;
+pub mod manifest;
+pub mod template;
+pub mod draft;
\ No newline at end of file
@@ -26,6 +26,9 @@ pub struct Project
     pub created_at: String,
     pub env_vars: Option<HashMap<String, String>>,
     pub persistent_volume_path: Option<String>,
+    /// GitHub projects only: redeploy on every push to `source_branch`
+    #[serde(default)]
+    pub auto_deploy: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize)]
@@ -49,7 +52,7 @@ pub struct ProjectDetailsResponse
     pub project: ProjectDetails,
 }
 
-#[derive(Serialize, Default)]
+#[derive(Clone, Debug, PartialEq, Serialize, Default)]
 pub struct DeployPayload
 {
     pub project_name: String,
@@ -82,6 +85,31 @@ pub struct ProjectMetrics
     pub cpu_usage: f64,
     pub memory_usage: f64,
     pub memory_limit: f64,
+    // Absent from events sent by older backends
+    #[serde(default)]
+    pub network_rx_bytes_per_sec: f64,
+    #[serde(default)]
+    pub network_tx_bytes_per_sec: f64,
+    #[serde(default)]
+    pub block_read_bytes_per_sec: f64,
+    #[serde(default)]
+    pub block_write_bytes_per_sec: f64,
+    #[serde(default)]
+    pub pids: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct MetricsSample
+{
+    pub timestamp: String,
+    #[serde(flatten)]
+    pub metrics: ProjectMetrics,
+}
+
+#[derive(Deserialize)]
+pub struct MetricsHistoryResponse
+{
+    pub samples: Vec<MetricsSample>,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize)]
@@ -93,6 +121,23 @@ pub struct GlobalMetrics
     pub total_memory_usage_mb: f64,
 }
 
+/// Latest resource usage of one project, as listed on the admin page.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct ProjectResourceUsage
+{
+    pub project_id: i32,
+    pub project_name: String,
+    pub owner: String,
+    #[serde(flatten)]
+    pub metrics: ProjectMetrics,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectResourceUsageResponse
+{
+    pub projects: Vec<ProjectResourceUsage>,
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize)]
 pub struct DownProjectInfo
 {
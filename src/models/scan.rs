@@ -0,0 +1,95 @@
+use serde::Deserialize;
+
+/// Grype severities, most severe first so that sorting by severity puts critical issues on top.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(from = "String")]
+pub enum Severity
+{
+    Critical,
+    High,
+    Medium,
+    Low,
+    Negligible,
+    Unknown,
+}
+
+impl Severity
+{
+    pub const ALL: [Severity; 6] = [
+        Severity::Critical,
+        Severity::High,
+        Severity::Medium,
+        Severity::Low,
+        Severity::Negligible,
+        Severity::Unknown,
+    ];
+
+    pub fn css_class(self) -> &'static str
+    {
+        match self
+        {
+            Severity::Critical => "severity-critical",
+            Severity::High => "severity-high",
+            Severity::Medium => "severity-medium",
+            Severity::Low => "severity-low",
+            Severity::Negligible => "severity-negligible",
+            Severity::Unknown => "severity-unknown",
+        }
+    }
+
+    pub fn translation_key(self) -> &'static str
+    {
+        match self
+        {
+            Severity::Critical => "project_dashboard.severity_critical",
+            Severity::High => "project_dashboard.severity_high",
+            Severity::Medium => "project_dashboard.severity_medium",
+            Severity::Low => "project_dashboard.severity_low",
+            Severity::Negligible => "project_dashboard.severity_negligible",
+            Severity::Unknown => "project_dashboard.severity_unknown",
+        }
+    }
+}
+
+// Grype capitalises severities, the backend may not
+impl From<String> for Severity
+{
+    fn from(value: String) -> Self
+    {
+        match value.to_ascii_lowercase().as_str()
+        {
+            "critical" => Severity::Critical,
+            "high" => Severity::High,
+            "medium" => Severity::Medium,
+            "low" => Severity::Low,
+            "negligible" => Severity::Negligible,
+            _ => Severity::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Vulnerability
+{
+    /// CVE or GHSA identifier
+    pub id: String,
+    pub severity: Severity,
+    pub package: String,
+    pub installed_version: String,
+    /// `None` when no fixed version has been released yet
+    pub fixed_version: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct ScanReport
+{
+    pub image_digest: Option<String>,
+    pub scanned_at: String,
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+#[derive(Deserialize)]
+pub struct ScanReportResponse
+{
+    pub report: ScanReport,
+}
\ No newline at end of file
@@ -0,0 +1,83 @@
+use crate::models::project::DeployPayload;
+
+/// A quick-start preset from the templates gallery. Names and descriptions are
+/// translated under `templates.{id}_name` and `templates.{id}_description`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectTemplate
+{
+    pub id: &'static str,
+    /// Repository name, expanded with `AppConfig::template_repo_url`
+    pub starter_repo: &'static str,
+    pub stack: &'static [&'static str],
+    /// Empty values are secrets left for the user to fill in
+    pub env_vars: &'static [(&'static str, &'static str)],
+    pub persistent_volume_path: Option<&'static str>,
+    pub create_database: bool,
+}
+
+pub const TEMPLATES: &[ProjectTemplate] = &[
+    ProjectTemplate
+    {
+        id: "static_nginx",
+        starter_repo: "hangar-template-static-nginx",
+        stack: &["nginx", "HTML/CSS"],
+        env_vars: &[],
+        persistent_volume_path: None,
+        create_database: false,
+    },
+    ProjectTemplate
+    {
+        id: "node_express",
+        starter_repo: "hangar-template-node-express",
+        stack: &["Node.js", "Express"],
+        env_vars: &[("NODE_ENV", "production")],
+        persistent_volume_path: Some("/app/uploads"),
+        create_database: false,
+    },
+    ProjectTemplate
+    {
+        id: "flask_mysql",
+        starter_repo: "hangar-template-flask-mysql",
+        stack: &["Python", "Flask", "MySQL"],
+        env_vars: &[("FLASK_ENV", "production"), ("SECRET_KEY", "")],
+        persistent_volume_path: None,
+        create_database: true,
+    },
+    ProjectTemplate
+    {
+        id: "rust_axum",
+        starter_repo: "hangar-template-rust-axum",
+        stack: &["Rust", "axum"],
+        env_vars: &[("RUST_LOG", "info")],
+        persistent_volume_path: None,
+        create_database: false,
+    },
+];
+
+impl ProjectTemplate
+{
+    pub fn find(id: &str) -> Option<&'static ProjectTemplate>
+    {
+        TEMPLATES.iter().find(|t| t.id == id)
+    }
+
+    /// Everything but the project name and the repository. The starter repository is not part
+    /// of the user's GitHub App installation: they generate their own copy and pick it instead.
+    pub fn to_payload(&self) -> DeployPayload
+    {
+        DeployPayload
+        {
+            env_vars: if self.env_vars.is_empty()
+            {
+                None
+            }
+            else
+            {
+                Some(self.env_vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect())
+            },
+            persistent_volume_path: self.persistent_volume_path.map(str::to_string),
+            create_database: self.create_database.then_some(true),
+            ..Default::default()
+        }
+    }
+}
\ No newline at end of file
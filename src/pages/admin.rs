@@ -1,11 +1,67 @@
+use std::rc::Rc;
+
+use crate::components::export_buttons::ExportButtons;
+use crate::components::top_consumers::TopConsumers;
 use crate::models::database::AdminDatabaseInfo;
-use crate::models::project::{DownProjectInfo, GlobalMetrics, Project};
+use crate::models::project::{DownProjectInfo, GlobalMetrics, Project, ProjectResourceUsage};
 use crate::router::AppRoute;
 use crate::services::project_service;
+use crate::utils::export::{download_rows, iso_timestamp, ExportFormat};
 use i18nrs::yew::use_translation;
+use gloo_timers::callback::Interval;
+use serde::Serialize;
 use yew::prelude::*;
 use yew_router::prelude::Link;
 
+const METRICS_POLL_INTERVAL_MS: u32 = 5000;
+/// One hour of samples at the polling interval
+const MAX_METRICS_SAMPLES: usize = 720;
+
+const METRICS_EXPORT_COLUMNS: [&str; 5] = [
+    "timestamp", "total_projects", "running_containers", "total_cpu_usage", "total_memory_usage_mb"
+];
+
+#[derive(Clone, PartialEq)]
+struct GlobalMetricsSample
+{
+    at_ms: f64,
+    metrics: GlobalMetrics,
+}
+
+#[derive(Clone, PartialEq, Default)]
+struct GlobalMetricsHistory
+{
+    samples: Vec<GlobalMetricsSample>,
+}
+
+impl Reducible for GlobalMetricsHistory
+{
+    type Action = GlobalMetricsSample;
+
+    fn reduce(self: Rc<Self>, sample: Self::Action) -> Rc<Self>
+    {
+        let mut next = (*self).clone();
+        next.samples.push(sample);
+
+        if next.samples.len() > MAX_METRICS_SAMPLES
+        {
+            next.samples.drain(0..(next.samples.len() - MAX_METRICS_SAMPLES));
+        }
+
+        next.into()
+    }
+}
+
+#[derive(Serialize)]
+struct GlobalMetricsExportRow
+{
+    timestamp: String,
+    total_projects: i64,
+    running_containers: u64,
+    total_cpu_usage: f64,
+    total_memory_usage_mb: f64,
+}
+
 fn format_downtime(seconds: i64) -> String 
 {
     if seconds < 60 
@@ -31,27 +87,61 @@ pub fn admin() -> Html
 {
     let (i18n, _) = use_translation();
     
-    let metrics = use_state(|| None::<GlobalMetrics>);
+    // Global metrics polled since the page was opened, oldest first
+    let metrics_history = use_reducer(GlobalMetricsHistory::default);
+    let resource_usage = use_state(|| None::<Vec<ProjectResourceUsage>>);
     let down_projects = use_state(|| None::<Vec<DownProjectInfo>>);
     let all_projects = use_state(|| None::<Vec<Project>>);
     let all_databases = use_state(|| None::<Vec<AdminDatabaseInfo>>);
 
+    // Keep global metrics and per-project usage live
+    {
+        let metrics_history = metrics_history.clone();
+        let resource_usage = resource_usage.clone();
+
+        use_effect_with((), move |_|
+        {
+            let poll = move ||
+            {
+                let metrics_history = metrics_history.clone();
+                wasm_bindgen_futures::spawn_local(async move
+                {
+                    match project_service::get_global_metrics_admin().await
+                    {
+                        Ok(m) => metrics_history.dispatch(GlobalMetricsSample
+                        {
+                            at_ms: js_sys::Date::now(),
+                            metrics: m,
+                        }),
+                        Err(e) => gloo_console::warn!("Failed to refresh global metrics:", e.error_code),
+                    }
+                });
+
+                let resource_usage = resource_usage.clone();
+                wasm_bindgen_futures::spawn_local(async move
+                {
+                    match project_service::get_projects_resource_usage_admin().await
+                    {
+                        Ok(usage) => resource_usage.set(Some(usage)),
+                        Err(e) => gloo_console::warn!("Failed to refresh project resource usage:", e.error_code),
+                    }
+                });
+            };
+
+            poll();
+            let interval = Interval::new(METRICS_POLL_INTERVAL_MS, poll);
+
+            move || drop(interval)
+        });
+    }
+
     {
-        let metrics = metrics.clone();
         let down_projects = down_projects.clone();
         let all_projects = all_projects.clone();
         let all_databases = all_databases.clone();
 
         use_effect_with((), move |_| 
         {
-            // Fetch Global Metrics
-            wasm_bindgen_futures::spawn_local(async move 
-            {
-                if let Ok(m) = project_service::get_global_metrics_admin().await 
-                {
-                    metrics.set(Some(m));
-                }
-            });
             // Fetch Down Projects
             wasm_bindgen_futures::spawn_local(async move 
             {
@@ -80,6 +170,28 @@ pub fn admin() -> Html
         });
     }
 
+    let on_export_metrics =
+    {
+        let metrics_history = metrics_history.clone();
+        Callback::from(move |format: ExportFormat|
+        {
+            let rows = metrics_history
+                .samples
+                .iter()
+                .map(|s| GlobalMetricsExportRow
+                {
+                    timestamp: iso_timestamp(s.at_ms),
+                    total_projects: s.metrics.total_projects,
+                    running_containers: s.metrics.running_containers,
+                    total_cpu_usage: s.metrics.total_cpu_usage,
+                    total_memory_usage_mb: s.metrics.total_memory_usage_mb,
+                })
+                .collect::<Vec<_>>();
+
+            download_rows("hangar-global-metrics", &rows, &METRICS_EXPORT_COLUMNS, format);
+        })
+    };
+
     html! 
     {
         <div>
@@ -87,9 +199,12 @@ pub fn admin() -> Html
             
             // Section Métriques
             <div class="card" style="margin-bottom: var(--spacing-lg)">
-                <h2>{ i18n.t("admin.global_metrics_title") }</h2>
+                <div style="display: flex; justify-content: space-between; align-items: center; flex-wrap: wrap; gap: var(--spacing-sm);">
+                    <h2>{ i18n.t("admin.global_metrics_title") }</h2>
+                    <ExportButtons on_export={on_export_metrics} disabled={metrics_history.samples.is_empty()} />
+                </div>
                 {
-                    if let Some(m) = &*metrics 
+                    if let Some(m) = metrics_history.samples.last().map(|s| &s.metrics) 
                     {
                         html! 
                         {
@@ -108,6 +223,21 @@ pub fn admin() -> Html
                 }
             </div>
 
+            // Section Top consommateurs
+            <div class="card" style="margin-bottom: var(--spacing-lg)">
+                <h2>{ i18n.t("admin.top_consumers_title") }</h2>
+                {
+                    if let Some(usage) = &*resource_usage
+                    {
+                        html! { <TopConsumers projects={usage.clone()} /> }
+                    }
+                    else
+                    {
+                        html! { <p>{ i18n.t("common.loading") }</p> }
+                    }
+                }
+            </div>
+
             // Section Alertes (Projets Down)
             <div class="card" style="margin-bottom: var(--spacing-lg); border-color: var(--color-danger)">
                 <h2>{ "Alerts: Down Projects" }</h2>
@@ -38,7 +38,7 @@ pub fn auth_callback() -> Html
                         }
                         Err(e) => 
                         {
-                            log::error!("Login failed: {}", e);
+                            log::error!("Login failed: {}", e.error_code);
                             error_message.set(Some(i18n.t("auth.login_failed")));
                         }
                     }
@@ -1,65 +1,287 @@
 use std::collections::{HashMap, HashSet};
 
+use i18nrs::I18n;
 use i18nrs::yew::use_translation;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::components::build_console::BuildConsole;
 use crate::components::deployment_progress::{DeploymentProgress, ProgressContext};
+use crate::components::github_source_picker::GithubSourcePicker;
+use crate::components::scan_report_table::ScanReportTable;
+use crate::contexts::config_context::use_config;
+use crate::contexts::sse_context::{BuildLogAction, BuildLogBuffer, DeploymentTimeline, DeploymentTimelineAction};
 use crate::contexts::user_context::use_user;
 use crate::hooks::use_sse::use_sse_creation;
+use crate::models::draft::{CreateProjectDraft, DeployMethod, WizardStep};
 use crate::models::project::DeployPayload;
+use crate::models::template::ProjectTemplate;
+use crate::utils::manifest::{parse_manifest, ManifestFormat};
 use crate::router::AppRoute;
 use crate::services::database_service;
-use crate::services::project_service::{self, ApiError};
-use crate::services::sse_service::{DeploymentStage, SseEvent};
+use crate::services::api_client::ApiError;
+use crate::services::draft_service;
+use crate::services::project_service;
+use crate::services::sse_service::{BuildLogEvent, DeploymentEvent, DeploymentStage, SseEvent};
+use crate::utils::scan_report::parse_scan_details;
+use crate::utils::validation::{self, ValidationError};
 
-#[derive(PartialEq, Clone, Copy)]
-enum DeployMethod
+fn handle_input(state: UseStateHandle<String>) -> Callback<InputEvent>
 {
-    GitHub,
-    Direct,
-    Database,
+    Callback::from(move |e: InputEvent| 
+    {
+        let value = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+        state.set(value);
+    })
 }
 
-const GITHUB_APP_NAME: &str = "hangar-app";
-
-fn handle_change_textarea(state: UseStateHandle<String>) -> Callback<Event>
+fn handle_input_textarea(state: UseStateHandle<String>) -> Callback<InputEvent>
 {
-    Callback::from(move |e: Event| 
+    Callback::from(move |e: InputEvent| 
     {
         let value = e.target_unchecked_into::<web_sys::HtmlTextAreaElement>().value();
         state.set(value);
     })
 }
 
+/// Empty required fields are only flagged once the user has tried to submit.
+fn visible_error(result: Result<(), ValidationError>, submit_attempted: bool) -> Option<ValidationError>
+{
+    match result
+    {
+        Err(ValidationError::Required) if !submit_attempted => None,
+        Err(e) => Some(e),
+        Ok(()) => None,
+    }
+}
+
+fn render_field_error(error: Option<&ValidationError>, i18n: &I18n) -> Html
+{
+    let Some(error) = error
+    else
+    {
+        return html! {};
+    };
+
+    let mut message = i18n.t(error.translation_key());
+    if let Some(line) = error.line()
+    {
+        message = message.replace("{line}", &line.to_string());
+    }
+    html! { <small class="field-error">{ message }</small> }
+}
+
+fn input_class(error: Option<&ValidationError>) -> Classes
+{
+    classes!("text-input", error.is_some().then_some("input-invalid"))
+}
+
+fn parse_participants(participants: &str) -> HashSet<String>
+{
+    participants
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn env_var_keys(env_vars: &str) -> Vec<String>
+{
+    env_vars
+        .lines()
+        .filter_map(|line| line.trim().split_once('='))
+        .map(|(k, _)| k.trim().to_string())
+        .filter(|k| !k.is_empty())
+        .collect()
+}
+
+/// Step holding the field a backend error is about, so the user lands next to it.
+fn step_for_error(error_code: &str) -> Option<WizardStep>
+{
+    match error_code
+    {
+        "INVALID_PROJECT_NAME" | "PROJECT_NAME_TAKEN" | "INVALID_IMAGE_URL" | "GITHUB_REPO_NOT_ACCESSIBLE" | "GITHUB_PACKAGE_NOT_PUBLIC" => Some(WizardStep::Source),
+        "OWNER_CANNOT_BE_PARTICIPANT" => Some(WizardStep::Collaborators),
+        _ => None,
+    }
+}
+
+/// The deployment submitted from this form, among all those the creation stream reports.
+/// Its id comes from the deploy response or from the first deployment event named after it.
+struct DeployingProject
+{
+    name: String,
+    id: Option<i32>,
+}
+
+impl DeployingProject
+{
+    fn owns_deployment(&mut self, event: &DeploymentEvent) -> bool
+    {
+        match self.id
+        {
+            Some(id) => id == event.project_id,
+            None if event.project_name == self.name =>
+            {
+                self.id = Some(event.project_id);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 #[function_component(CreateProject)]
 pub fn create_project() -> Html
 {
     let (i18n, _) = use_translation();
     let user_context = use_user();
+    let config = use_config();
     let navigator = use_navigator().unwrap();
     let location = use_location().unwrap();
 
-    let project_name = use_state(String::new);
-    let participants_str = use_state(String::new);
-    let github_repo_url = use_state(String::new);
-    let github_branch = use_state(String::new);
-    let github_root_dir = use_state(String::new);
-    let image_url = use_state(String::new);
-    let env_vars_str = use_state(String::new);
-    let volume_path_str = use_state(String::new);
-    let create_db_with_project = use_state(|| false);
-
-    let active_method = use_state(|| DeployMethod::GitHub);
+    // Restored once, so a reload or the GitHub App round trip keeps what was typed
+    let restored_draft = use_memo((), |_| draft_service::load_draft());
+    let initial = (*restored_draft).clone().unwrap_or_default();
+
+    let project_name = use_state(|| initial.project_name.clone());
+    let participants_str = use_state(|| initial.participants.clone());
+    let github_repo_url = use_state(|| initial.github_repo_url.clone());
+    let github_branch = use_state(|| initial.github_branch.clone());
+    let github_root_dir = use_state(|| initial.github_root_dir.clone());
+    let github_source_valid = use_state(|| true);
+    let image_url = use_state(|| initial.image_url.clone());
+    let env_vars_str = use_state(|| initial.env_vars.clone());
+    // Keys brought back without their value, which must be typed in again before deploying
+    let missing_env_values = use_state(|| initial.missing_env_values.clone());
+    let volume_path_str = use_state(|| initial.volume_path.clone());
+    let create_db_with_project = use_state(|| initial.create_database);
+
+    let active_method = use_state(|| initial.method);
+    let step = use_state(|| initial.step);
     let is_loading = use_state(|| false);
     let error = use_state(|| None::<ApiError>);
     let show_success_banner = use_state(|| false);
+    let show_draft_banner = use_state(|| restored_draft.is_some());
+    let submit_attempted = use_state(|| false);
+    let imported_manifest = use_state(|| None::<String>);
+    let applied_template = use_state(|| None::<&'static ProjectTemplate>);
+
+    let deploying = use_mut_ref(|| None::<DeployingProject>);
 
-    let sse_state = use_sse_creation();
-    let deployment_stage = use_state(|| None::<DeploymentStage>);
+    let build_log = use_reducer(BuildLogBuffer::default);
+    let on_build_log =
+    {
+        let build_log = build_log.clone();
+        let deploying = deploying.clone();
+        Callback::from(move |event: BuildLogEvent|
+        {
+            let project_id = deploying.borrow().as_ref().and_then(|d| d.id);
+            if project_id == Some(event.project_id)
+            {
+                build_log.dispatch(BuildLogAction::Append(event));
+            }
+        })
+    };
+
+    let deployment_timeline = use_reducer(DeploymentTimeline::default);
+    let on_deployment =
+    {
+        let deployment_timeline = deployment_timeline.clone();
+        let deploying = deploying.clone();
+        Callback::from(move |event: DeploymentEvent|
+        {
+            if deploying.borrow_mut().as_mut().is_some_and(|d| d.owns_deployment(&event))
+            {
+                deployment_timeline.dispatch(DeploymentTimelineAction::Record(event));
+            }
+        })
+    };
+
+    let sse_state = use_sse_creation(on_build_log, on_deployment);
+    let deployment_event = use_state(|| None::<DeploymentEvent>);
+
+    let is_terminal = matches!(
+        deployment_event.as_ref().map(|e| &e.stage),
+        Some(DeploymentStage::Completed { .. }) | Some(DeploymentStage::Failed { .. }) | Some(DeploymentStage::Cancelled)
+    );
+    let is_deploying = *is_loading || (deployment_event.is_some() && !is_terminal);
+
+    // Mirrors INVALID_PROJECT_NAME / INVALID_IMAGE_URL so mistakes show up while typing
+    let name_error = visible_error(validation::validate_project_name(&project_name), *submit_attempted);
+    let image_error = if *active_method == DeployMethod::Direct
+    {
+        visible_error(validation::validate_image_reference(&image_url), *submit_attempted)
+    }
+    else
+    {
+        None
+    };
+    let volume_error = if volume_path_str.trim().is_empty()
+    {
+        None
+    }
+    else
+    {
+        validation::validate_volume_path(&volume_path_str).err()
+    };
+    let env_error = validation::validate_env_vars(&env_vars_str)
+        .and_then(|_| validation::validate_required_env_values(&env_vars_str, &missing_env_values))
+        .err();
+    let owner_is_participant = user_context
+        .user
+        .as_ref()
+        .is_some_and(|u| parse_participants(&participants_str).contains(&u.login));
+
+    let github_source_invalid = *active_method == DeployMethod::GitHub
+        && (!*github_source_valid || github_repo_url.trim().is_empty());
+    let source_valid = validation::validate_project_name(&project_name).is_ok()
+        && match *active_method
+        {
+            DeployMethod::GitHub => !github_source_invalid,
+            DeployMethod::Direct => validation::validate_image_reference(&image_url).is_ok(),
+            DeployMethod::Database => true,
+        };
+    let step_valid = |s: WizardStep| match s
+    {
+        WizardStep::Source => source_valid,
+        WizardStep::Configuration => env_error.is_none() && volume_error.is_none(),
+        WizardStep::Collaborators => !owner_is_participant,
+        WizardStep::Review => true,
+    };
+    let first_invalid_step = WizardStep::ALL.into_iter().find(|s| !step_valid(*s));
+    // A step can be opened once every step before it is filled in correctly
+    let step_reachable = |s: WizardStep| WizardStep::ALL[..s.index()].iter().all(|p| step_valid(*p));
 
-    let is_terminal = matches!(&*deployment_stage, Some(DeploymentStage::Completed { .. }) | Some(DeploymentStage::Failed { .. }));
-    let is_deploying = *is_loading || (deployment_stage.is_some() && !is_terminal);
+    let draft = CreateProjectDraft
+    {
+        method: *active_method,
+        step: *step,
+        project_name: (*project_name).clone(),
+        github_repo_url: (*github_repo_url).clone(),
+        github_branch: (*github_branch).clone(),
+        github_root_dir: (*github_root_dir).clone(),
+        image_url: (*image_url).clone(),
+        env_vars: (*env_vars_str).clone(),
+        missing_env_values: (*missing_env_values).clone(),
+        volume_path: (*volume_path_str).clone(),
+        create_database: *create_db_with_project,
+        participants: (*participants_str).clone(),
+        saved_at: 0.0,
+    };
+
+    use_effect_with(draft, |draft|
+    {
+        draft_service::save_draft(draft);
+        || ()
+    });
+
+    let template_id = location
+        .query_str()
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|p: &str| p.strip_prefix("template="))
+        .map(str::to_string);
 
     // Handle GitHub success banner
     {
@@ -76,7 +298,8 @@ pub fn create_project() -> Html
 
     // Process SSE events
     {
-        let deployment_stage = deployment_stage.clone();
+        let deployment_event = deployment_event.clone();
+        let deploying = deploying.clone();
         let navigator = navigator.clone();
         let is_loading = is_loading.clone();
         let error = error.clone();
@@ -87,12 +310,15 @@ pub fn create_project() -> Html
             {
                 match last_event
                 {
-                    SseEvent::Deployment(event) =>
+                    // `on_deployment` has already seen the event and learnt the id if it is ours
+                    SseEvent::Deployment(event) if deploying.borrow().as_ref().and_then(|d| d.id) == Some(event.project_id) =>
                     {
-                        deployment_stage.set(Some(event.stage.clone()));
+                        deployment_event.set(Some(event.clone()));
 
                         if let DeploymentStage::Completed { .. } = &event.stage
                         {
+                            draft_service::clear_draft();
+
                             let project_id = event.project_id;
                             let navigator = navigator.clone();
                             let is_loading = is_loading.clone();
@@ -102,7 +328,7 @@ pub fn create_project() -> Html
                                 is_loading.set(false);
                                 navigator.push(&AppRoute::ProjectDashboard { id: project_id });
                             }).forget();
-                        }
+                        } 
 
                         if let DeploymentStage::Failed { error: err_msg, .. } = &event.stage
                         {
@@ -112,12 +338,17 @@ pub fn create_project() -> Html
                                 error_code: "DEPLOYMENT_FAILED".to_string(),
                                 details: Some(err_msg.clone()),
                             }));
-                        }
-                    }
+                        } 
+
+                        if event.stage == DeploymentStage::Cancelled
+                        {
+                            is_loading.set(false);
+                        } 
+                    } 
                     SseEvent::System(event) =>
                     {
                         gloo_console::log!(format!("[{:?}] {}", event.level, event.message));
-                    }
+                    } 
                     _ =>
                     {}
                 }
@@ -126,6 +357,53 @@ pub fn create_project() -> Html
         });
     }
 
+    let on_next =
+    {
+        let step = step.clone();
+        let error = error.clone();
+        let submit_attempted = submit_attempted.clone();
+        let current_valid = step_valid(*step);
+        let github_issue = *step == WizardStep::Source
+            && github_source_invalid
+            && !github_repo_url.trim().is_empty();
+
+        Callback::from(move |_: ()|
+        {
+            if !current_valid
+            {
+                submit_attempted.set(true);
+                // Catches typos before the backend reports GITHUB_REPO_NOT_ACCESSIBLE
+                let code = if github_issue { "GITHUB_SOURCE_INVALID" } else { "FORM_INVALID" };
+                error.set(Some(ApiError::new(code)));
+                return;
+            }
+
+            error.set(None);
+            if let Some(next) = step.next()
+            {
+                step.set(next);
+            }
+        })
+    };
+
+    let on_back =
+    {
+        let step = step.clone();
+        Callback::from(move |_| 
+        {
+            if let Some(previous) = step.previous()
+            {
+                step.set(previous);
+            }
+        })
+    };
+
+    let go_to_step = |target: WizardStep|
+    {
+        let step = step.clone();
+        Callback::from(move |_| step.set(target))
+    };
+
     let on_submit = 
     {
         let project_name = project_name.clone();
@@ -135,21 +413,48 @@ pub fn create_project() -> Html
         let github_root_dir = github_root_dir.clone();
         let image_url = image_url.clone();
         let active_method = active_method.clone();
+        let step = step.clone();
         let is_loading = is_loading.clone();
         let error = error.clone();
         let navigator = navigator.clone();
-        let user_login = user_context.user.as_ref().map(|u| u.login.clone());
         let env_vars_str = env_vars_str.clone();
         let volume_path_str = volume_path_str.clone();
         let create_db_with_project = create_db_with_project.clone();
-        let deployment_stage = deployment_stage.clone();
+        let deployment_event = deployment_event.clone();
+        let deployment_timeline = deployment_timeline.clone();
+        let build_log = build_log.clone();
+        let deploying = deploying.clone();
+        let submit_attempted = submit_attempted.clone();
+        let on_next = on_next.clone();
 
         Callback::from(move |e: SubmitEvent| 
         {
             e.prevent_default();
+
+            if *active_method != DeployMethod::Database
+            {
+                // Enter in a field of an earlier step moves on instead of deploying
+                if *step != WizardStep::Review
+                {
+                    on_next.emit(());
+                    return;
+                }
+
+                if let Some(invalid_step) = first_invalid_step
+                {
+                    submit_attempted.set(true);
+                    step.set(invalid_step);
+                    error.set(Some(ApiError::new("FORM_INVALID")));
+                    return;
+                }
+            }
+
             is_loading.set(true);
             error.set(None);
-            deployment_stage.set(None);
+            deployment_event.set(None);
+            deployment_timeline.dispatch(DeploymentTimelineAction::Clear);
+            build_log.dispatch(BuildLogAction::Clear);
+            *deploying.borrow_mut() = Some(DeployingProject { name: (*project_name).clone(), id: None });
 
             let project_name = project_name.clone();
             let participants_str = participants_str.clone();
@@ -158,10 +463,11 @@ pub fn create_project() -> Html
             let github_root_dir = github_root_dir.clone();
             let image_url = image_url.clone();
             let active_method = active_method.clone();
+            let step = step.clone();
             let is_loading = is_loading.clone();
+            let deploying = deploying.clone();
             let error = error.clone();
             let navigator = navigator.clone();
-            let user_login = user_login.clone();
             let env_vars_str = env_vars_str.clone();
             let volume_path_str = volume_path_str.clone();
             let create_db_with_project = create_db_with_project.clone();
@@ -174,29 +480,12 @@ pub fn create_project() -> Html
                     {
                         Ok(db) => navigator.push(&AppRoute::DatabaseDashboard { id: db.id }),
                         Err(e) => error.set(Some(e)),
-                    }
+                    } 
                     is_loading.set(false);
                     return;
                 }
 
-                let participants_set: HashSet<String> = (*participants_str)
-                    .split(',')
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect();
-
-                if let Some(login) = &user_login
-                    && participants_set.contains(login)
-                    {
-                        error.set(Some(ApiError 
-                        {
-                            error_code: "OWNER_CANNOT_BE_PARTICIPANT".to_string(),
-                            details: None,
-                        }));
-                        is_loading.set(false);
-                        return;
-                    }
-                let participants: Vec<String> = participants_set.into_iter().collect();
+                let participants: Vec<String> = parse_participants(&participants_str).into_iter().collect();
 
                 let env_vars: HashMap<String, String> = (*env_vars_str)
                     .lines()
@@ -212,24 +501,24 @@ pub fn create_project() -> Html
                     env_vars: if env_vars.is_empty()
                     {
                         None
-                    }
-                    else
+                    } 
+                    else 
                     {
                         Some(env_vars)
                     },
                     persistent_volume_path: if (*volume_path_str).trim().is_empty()
                     {
                         None
-                    }
-                    else
+                    } 
+                    else 
                     {
                         Some((*volume_path_str).trim().to_string())
                     },
                     create_database: if *create_db_with_project
                     {
                         Some(true)
-                    }
-                    else
+                    } 
+                    else 
                     {
                         None
                     },
@@ -244,24 +533,24 @@ pub fn create_project() -> Html
                         payload.github_branch = if (*github_branch).trim().is_empty()
                         {
                             None
-                        }
-                        else
+                        } 
+                        else 
                         {
                             Some((*github_branch).trim().to_string())
                         };
                         payload.github_root_dir = if (*github_root_dir).trim().is_empty()
                         {
                             None
-                        }
-                        else
+                        } 
+                        else 
                         {
                             Some((*github_root_dir).trim().to_string())
                         };
-                    }
+                    } 
                     DeployMethod::Direct =>
                     {
                         payload.image_url = Some((*image_url).clone());
-                    }
+                    } 
                     DeployMethod::Database =>
                     {}
                 }
@@ -270,29 +559,191 @@ pub fn create_project() -> Html
 
                 match result
                 {
-                    Ok(_) =>
+                    Ok(details) =>
                     {
                         // SSE will handle the redirect
-                    }
+                        if let Some(deploying) = deploying.borrow_mut().as_mut()
+                        {
+                            deploying.id = Some(details.project.id);
+                        }
+                    } 
                     Err(api_error) =>
                     {
                         is_loading.set(false);
+                        if let Some(error_step) = step_for_error(&api_error.error_code)
+                        {
+                            step.set(error_step);
+                        } 
                         error.set(Some(api_error));
-                    }
+                    } 
                 }
             });
         })
     };
 
-    let handle_change = |state: UseStateHandle<String>| 
+    let set_value = |state: UseStateHandle<String>| Callback::from(move |value: String| state.set(value));
+
+    // Fills the form from a payload built elsewhere, e.g. an uploaded manifest
+    let apply_payload =
+    {
+        let project_name = project_name.clone();
+        let participants_str = participants_str.clone();
+        let github_repo_url = github_repo_url.clone();
+        let github_branch = github_branch.clone();
+        let github_root_dir = github_root_dir.clone();
+        let image_url = image_url.clone();
+        let env_vars_str = env_vars_str.clone();
+        let missing_env_values = missing_env_values.clone();
+        let volume_path_str = volume_path_str.clone();
+        let create_db_with_project = create_db_with_project.clone();
+        let active_method = active_method.clone();
+        let step = step.clone();
+
+        Callback::from(move |payload: DeployPayload|
+        {
+            if payload.github_repo_url.is_some()
+            {
+                active_method.set(DeployMethod::GitHub);
+            }
+            else if payload.image_url.is_some()
+            {
+                active_method.set(DeployMethod::Direct);
+            }
+
+            let mut env_vars: Vec<(String, String)> = payload.env_vars.unwrap_or_default().into_iter().collect();
+            env_vars.sort();
+
+            step.set(WizardStep::Source);
+            project_name.set(payload.project_name);
+            participants_str.set(payload.participants.join(", "));
+            github_repo_url.set(payload.github_repo_url.unwrap_or_default());
+            github_branch.set(payload.github_branch.unwrap_or_default());
+            github_root_dir.set(payload.github_root_dir.unwrap_or_default());
+            image_url.set(payload.image_url.unwrap_or_default());
+            // Manifests carry no values and templates leave secrets empty
+            missing_env_values.set(env_vars.iter().filter(|(_, v)| v.is_empty()).map(|(k, _)| k.clone()).collect());
+            env_vars_str.set(env_vars.into_iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join("\n"));
+            volume_path_str.set(payload.persistent_volume_path.unwrap_or_default());
+            create_db_with_project.set(payload.create_database.unwrap_or(false));
+        })
+    };
+
+    // Pre-fill from the templates gallery
     {
+        let apply_payload = apply_payload.clone();
+        let applied_template = applied_template.clone();
+        let show_draft_banner = show_draft_banner.clone();
+        let active_method = active_method.clone();
+        let navigator = navigator.clone();
+
+        use_effect_with(template_id, move |template_id|
+        {
+            if let Some(template) = template_id.as_deref().and_then(ProjectTemplate::find)
+            {
+                apply_payload.emit(template.to_payload());
+                // Templates build from a copy of their starter repository, picked in the GitHub source step
+                active_method.set(DeployMethod::GitHub);
+                applied_template.set(Some(template));
+                show_draft_banner.set(false);
+                // Otherwise a reload would apply the template again over the saved draft
+                navigator.replace(&AppRoute::CreateProject);
+            }
+            || ()
+        });
+    }
+
+    let on_manifest_upload =
+    {
+        let apply_payload = apply_payload.clone();
+        let imported_manifest = imported_manifest.clone();
+        let error = error.clone();
+
         Callback::from(move |e: Event| 
         {
-            let value = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
-            state.set(value);
+            let input = e.target_unchecked_into::<web_sys::HtmlInputElement>();
+            let Some(file) = input.files().and_then(|files| files.get(0))
+            else
+            {
+                return;
+            };
+            // Lets the same file be picked again after fixing it
+            input.set_value("");
+
+            let filename = file.name();
+            let Some(format) = ManifestFormat::from_filename(&filename)
+            else
+            {
+                error.set(Some(ApiError
+                {
+                    error_code: "INVALID_MANIFEST".to_string(),
+                    details: Some(filename),
+                }));
+                return;
+            };
+
+            let apply_payload = apply_payload.clone();
+            let imported_manifest = imported_manifest.clone();
+            let error = error.clone();
+
+            wasm_bindgen_futures::spawn_local(async move 
+            {
+                let content = wasm_bindgen_futures::JsFuture::from(file.text())
+                    .await
+                    .ok()
+                    .and_then(|text| text.as_string())
+                    .unwrap_or_default();
+
+                match parse_manifest(&content, format)
+                {
+                    Ok(manifest) =>
+                    {
+                        error.set(None);
+                        apply_payload.emit(manifest.into_payload());
+                        imported_manifest.set(Some(filename));
+                    } 
+                    Err(details) =>
+                    {
+                        imported_manifest.set(None);
+                        error.set(Some(ApiError 
+                        {
+                            error_code: "INVALID_MANIFEST".to_string(),
+                            details: Some(details),
+                        }));
+                    } 
+                }
+            });
+        })
+    };
+
+    let on_discard_draft =
+    {
+        let apply_payload = apply_payload.clone();
+        let active_method = active_method.clone();
+        let show_draft_banner = show_draft_banner.clone();
+        let submit_attempted = submit_attempted.clone();
+        let imported_manifest = imported_manifest.clone();
+        let applied_template = applied_template.clone();
+        let error = error.clone();
+
+        Callback::from(move |_| 
+        {
+            draft_service::clear_draft();
+            apply_payload.emit(DeployPayload::default());
+            active_method.set(DeployMethod::GitHub);
+            show_draft_banner.set(false);
+            submit_attempted.set(false);
+            imported_manifest.set(None);
+            applied_template.set(None);
+            error.set(None);
         })
     };
 
+    let on_github_validity_change =
+    {
+        let github_source_valid = github_source_valid.clone();
+        Callback::from(move |valid: bool| github_source_valid.set(valid))
+    };
+
     let handle_checkbox_change = 
     {
         let create_db_with_project = create_db_with_project.clone();
@@ -321,6 +772,12 @@ pub fn create_project() -> Html
         })
     };
 
+    let on_close_draft_banner =
+    {
+        let show_draft_banner = show_draft_banner.clone();
+        Callback::from(move |_| show_draft_banner.set(false))
+    };
+
     let render_error = |err: &ApiError| 
     {
         let error_key = format!("errors.{}", err.error_code);
@@ -341,11 +798,7 @@ pub fn create_project() -> Html
                 {
                     if err.error_code == "GITHUB_ACCOUNT_NOT_LINKED" 
                     {
-                        let github_app_install_url = format!
-                        (
-                            "https://github.com/apps/{}/installations/new",
-                            GITHUB_APP_NAME
-                        );
+                        let github_app_install_url = config.github_app_install_url();
                         html! 
                         {
                             <div style="margin-top: var(--spacing-md)">
@@ -354,7 +807,7 @@ pub fn create_project() -> Html
                                     { i18n.t("create_project.link_github_button") }
                                 </a>
                             </div>
-                        }
+                        } 
                     } 
                     else if err.error_code == "GITHUB_REPO_NOT_ACCESSIBLE" 
                     {
@@ -366,29 +819,52 @@ pub fn create_project() -> Html
                                     { i18n.t("create_project.link_github_button") }
                                 </a>
                             </div>
-                        }
+                        } 
+                    } 
+                    else if err.error_code == "INVALID_MANIFEST"
+                        && let Some(details) = &err.details
+                    {
+                        html! 
+                        {
+                            <div class="error-details-box">
+                                <pre><code>{ details.clone() }</code></pre>
+                            </div>
+                        } 
                     } 
                     else if err.error_code == "IMAGE_SCAN_FAILED" 
                     {
                         if let Some(details) = &err.details 
                         {
-                            html! 
+                            if let Some(vulnerabilities) = parse_scan_details(details)
                             {
-                                <div class="error-details-box">
-                                    <strong>{ "Grype Security Report:" }</strong>
-                                    <pre><code>{ details.clone() }</code></pre>
-                                </div>
+                                html! 
+                                {
+                                    <div class="error-details-box">
+                                        <strong>{ i18n.t("project_dashboard.card_title_security_scan") }</strong>
+                                        <ScanReportTable vulnerabilities={vulnerabilities} />
+                                    </div>
+                                } 
+                            }
+                            else
+                            {
+                                html! 
+                                {
+                                    <div class="error-details-box">
+                                        <strong>{ "Grype Security Report:" }</strong>
+                                        <pre><code>{ details.clone() }</code></pre>
+                                    </div>
+                                } 
                             }
                         } 
                         else 
                         {
                             html! {}
-                        }
+                        } 
                     } 
                     else 
                     {
                         html! {}
-                    }
+                    } 
                 }
             </div>
         }
@@ -406,6 +882,230 @@ pub fn create_project() -> Html
         }
     };
 
+    let step_indicator = html!
+    {
+        <ol class="wizard-steps">
+            {
+                for WizardStep::ALL.iter().map(|&s|
+                {
+                    let class = classes!(
+                        "wizard-step",
+                        (s == *step).then_some("active"),
+                        (s.index() < step.index()).then_some("done"),
+                    );
+                    html!
+                    {
+                        <li class={class}>
+                            <button type="button"
+                                onclick={go_to_step(s)}
+                                disabled={is_deploying || s == *step || !step_reachable(s)}>
+                                <span class="wizard-step-number">{ s.index() + 1 }</span>
+                                { i18n.t(s.translation_key()) }
+                            </button>
+                        </li>
+                    } 
+                })
+            }
+        </ol>
+    };
+
+    let review_row = |label: String, value: Html, edit_step: WizardStep|
+    {
+        html!
+        {
+            <div class="wizard-review-row">
+                <dt>{ label }</dt>
+                <dd>{ value }</dd>
+                <button type="button" class="wizard-edit-button" onclick={go_to_step(edit_step)} disabled={is_deploying}>
+                    { i18n.t("create_project.review_edit") }
+                </button>
+            </div>
+        }
+    };
+    let or_none = |value: &str|
+    {
+        if value.trim().is_empty()
+        {
+            html! { <span class="wizard-review-empty">{ i18n.t("create_project.review_none") }</span> }
+        }
+        else
+        {
+            html! { <code>{ value.trim().to_string() }</code> }
+        }
+    };
+
+    let step_body = match *step
+    {
+        WizardStep::Source => html!
+        {
+            <>
+                <div class="form-group">
+                    <label for="project_name">{ i18n.t("create_project.name_label") }</label>
+                    <input type="text" id="project_name" class={input_class(name_error.as_ref())}
+                        placeholder={i18n.t("create_project.name_placeholder")}
+                        value={(*project_name).clone()}
+                        oninput={handle_input(project_name.clone())}
+                        required=true
+                        disabled={is_deploying} />
+                    { render_field_error(name_error.as_ref(), &i18n) }
+                    <small style="color: var(--color-text-secondary)">{ i18n.t("create_project.name_help") }</small>
+                </div>
+
+                if *active_method == DeployMethod::GitHub
+                {
+                    <GithubSourcePicker
+                        repo_url={(*github_repo_url).clone()}
+                        branch={(*github_branch).clone()}
+                        root_dir={(*github_root_dir).clone()}
+                        on_repo_url_change={set_value(github_repo_url.clone())}
+                        on_branch_change={set_value(github_branch.clone())}
+                        on_root_dir_change={set_value(github_root_dir.clone())}
+                        on_validity_change={on_github_validity_change.clone()}
+                        disabled={is_deploying}
+                    />
+                }
+                else
+                {
+                    <div class="form-group">
+                        <label for="image_url">{ i18n.t("create_project.image_label") }</label>
+                        <input type="text" id="image_url" class={input_class(image_error.as_ref())}
+                            placeholder={i18n.t("create_project.image_placeholder")}
+                            value={(*image_url).clone()}
+                            oninput={handle_input(image_url.clone())}
+                            required=true
+                            disabled={is_deploying} />
+                        { render_field_error(image_error.as_ref(), &i18n) }
+                    </div>
+                }
+            </>
+        },
+        WizardStep::Configuration => html!
+        {
+            <>
+                <div class="form-group">
+                    <label for="env_vars">{ i18n.t("create_project.env_vars_label") }</label>
+                    <textarea id="env_vars" class={input_class(env_error.as_ref())}
+                        placeholder="API_KEY=your_secret_key"
+                        value={(*env_vars_str).clone()}
+                        oninput={handle_input_textarea(env_vars_str.clone())}
+                        rows="4"
+                        disabled={is_deploying} />
+                    { render_field_error(env_error.as_ref(), &i18n) }
+                    <small style="color: var(--color-text-secondary)">{ i18n.t("create_project.env_vars_help") }</small>
+                </div>
+
+                <div class="form-group">
+                    <label for="volume_path">{ i18n.t("create_project.volume_path_label") }</label>
+                    <input type="text" id="volume_path" class={input_class(volume_error.as_ref())}
+                        placeholder="/data/uploads"
+                        value={(*volume_path_str).clone()}
+                        oninput={handle_input(volume_path_str.clone())}
+                        disabled={is_deploying} />
+                    { render_field_error(volume_error.as_ref(), &i18n) }
+                    <small style="color: var(--color-text-secondary)">
+                        { i18n.t("create_project.volume_path_help") }
+                    </small>
+                </div>
+
+                <div class="form-group">
+                    <label class="checkbox-label" for="create_db" style="display: flex; align-items: center; gap: var(--spacing-sm);">
+                        <input type="checkbox" id="create_db"
+                            checked={*create_db_with_project}
+                            onchange={handle_checkbox_change}
+                            disabled={is_deploying}
+                        />
+                        { i18n.t("create_project.create_db_checkbox") }
+                    </label>
+                </div>
+            </>
+        },
+        WizardStep::Collaborators => html!
+        {
+            <div class="form-group">
+                <label for="participants">{ i18n.t("create_project.participants_label") }</label>
+                <input type="text" id="participants"
+                    class={classes!("text-input", owner_is_participant.then_some("input-invalid"))}
+                    placeholder={i18n.t("create_project.participants_placeholder")}
+                    value={(*participants_str).clone()}
+                    oninput={handle_input(participants_str.clone())}
+                    disabled={is_deploying} />
+                if owner_is_participant
+                {
+                    <small class="field-error">{ i18n.t("errors.OWNER_CANNOT_BE_PARTICIPANT") }</small>
+                }
+                <small style="color: var(--color-text-secondary)">{ i18n.t("create_project.participants_help") }</small>
+            </div>
+        },
+        WizardStep::Review =>
+        {
+            let mut participants: Vec<String> = parse_participants(&participants_str).into_iter().collect();
+            participants.sort();
+            let method_label = if *active_method == DeployMethod::GitHub
+            {
+                i18n.t("create_project.github_tab")
+            }
+            else
+            {
+                i18n.t("create_project.direct_tab")
+            };
+            let database_label = if *create_db_with_project
+            {
+                i18n.t("create_project.review_yes")
+            }
+            else
+            {
+                i18n.t("create_project.review_no")
+            };
+
+            html!
+            {
+                <dl class="wizard-review">
+                    { review_row(i18n.t("create_project.review_method"), html! { method_label }, WizardStep::Source) }
+                    { review_row(i18n.t("create_project.name_label"), or_none(&project_name), WizardStep::Source) }
+                    { review_row(i18n.t("create_project.review_url"), html! { config.project_url(project_name.trim()) }, WizardStep::Source) }
+                    if *active_method == DeployMethod::GitHub
+                    {
+                        { review_row(i18n.t("create_project.github_repo_url_label"), or_none(&github_repo_url), WizardStep::Source) }
+                        { review_row(i18n.t("create_project.github_branch_label"), or_none(&github_branch), WizardStep::Source) }
+                        { review_row(i18n.t("create_project.github_root_dir_label"), or_none(&github_root_dir), WizardStep::Source) }
+                    } 
+                    else 
+                    {
+                        { review_row(i18n.t("create_project.image_label"), or_none(&image_url), WizardStep::Source) }
+                    } 
+                    { review_row(i18n.t("create_project.env_vars_label"), or_none(&env_var_keys(&env_vars_str).join(", ")), WizardStep::Configuration) }
+                    { review_row(i18n.t("create_project.volume_path_label"), or_none(&volume_path_str), WizardStep::Configuration) }
+                    { review_row(i18n.t("create_project.create_db_checkbox"), html! { database_label }, WizardStep::Configuration) }
+                    { review_row(i18n.t("create_project.participants_label"), or_none(&participants.join(", ")), WizardStep::Collaborators) }
+                </dl>
+            }
+        }
+    };
+
+    // Stays available after a failed or cancelled deployment so the user can fix and retry
+    let can_submit = deployment_event
+        .as_ref()
+        .is_none_or(|e| matches!(e.stage, DeploymentStage::Failed { .. } | DeploymentStage::Cancelled));
+    let submit_button = html!
+    {
+        <button type="submit" class="button-primary" disabled={is_deploying}>
+        {
+            if is_deploying
+            {
+                i18n.t("create_project.submit_button_loading")
+            }
+            else if *active_method == DeployMethod::Database
+            {
+                i18n.t("database.create_button")
+            }
+            else
+            {
+                i18n.t("create_project.submit_button")
+            }
+        }
+        </button>
+    };
+
     html! 
     {
         <div class="create-project-page" style="max-width: 700px; margin: auto;">
@@ -418,6 +1118,19 @@ pub fn create_project() -> Html
                 </div>
             }
 
+            if *show_draft_banner
+            {
+                <div class="success-banner">
+                    <p>{ i18n.t("create_project.draft_restored") }</p>
+                    <div style="display: flex; align-items: center; gap: var(--spacing-sm);">
+                        <button class="metrics-range-button" onclick={on_discard_draft} disabled={is_deploying}>
+                            { i18n.t("create_project.discard_draft_button") }
+                        </button>
+                        <button onclick={on_close_draft_banner}>{"✖"}</button>
+                    </div>
+                </div>
+            }
+
             <h1>{ i18n.t("create_project.title") }</h1>
             <p>
                 { i18n.t("create_project.documentation") }
@@ -426,14 +1139,49 @@ pub fn create_project() -> Html
                 </a>
             </p>
 
+            if let Some(template) = *applied_template
+            {
+                <div class="template-notice">
+                    <p>
+                        { i18n.t("create_project.template_applied").replace("{template}", &i18n.t(&format!("templates.{}_name", template.id))) }
+                    </p>
+                    <a href={format!("{}/generate", config.template_repo_url(template.starter_repo))}
+                        target="_blank" rel="noopener noreferrer" class="button-primary">
+                        { i18n.t("create_project.template_copy_repo_button") }
+                    </a>
+                </div>
+            }
+
+            <div class="manifest-import">
+                <label for="manifest_upload" class="metrics-range-button">
+                    { i18n.t("create_project.import_manifest_button") }
+                </label>
+                <input type="file" id="manifest_upload"
+                    accept=".json,.toml"
+                    onchange={on_manifest_upload}
+                    disabled={is_deploying} />
+                <small style="color: var(--color-text-secondary)">
+                {
+                    if let Some(filename) = &*imported_manifest
+                    {
+                        i18n.t("create_project.import_manifest_success").replace("{file}", filename)
+                    } 
+                    else 
+                    {
+                        i18n.t("create_project.import_manifest_help")
+                    } 
+                }
+                </small>
+            </div>
+
             <div class="tabs-container">
-                <button class={tab_class(DeployMethod::GitHub)} onclick={select_method(DeployMethod::GitHub)}>
+                <button class={tab_class(DeployMethod::GitHub)} onclick={select_method(DeployMethod::GitHub)} disabled={is_deploying}>
                     { i18n.t("create_project.github_tab") }
                 </button>
-                <button class={tab_class(DeployMethod::Direct)} onclick={select_method(DeployMethod::Direct)}>
+                <button class={tab_class(DeployMethod::Direct)} onclick={select_method(DeployMethod::Direct)} disabled={is_deploying}>
                     { i18n.t("create_project.direct_tab") }
                 </button>
-                <button class={tab_class(DeployMethod::Database)} onclick={select_method(DeployMethod::Database)}>
+                <button class={tab_class(DeployMethod::Database)} onclick={select_method(DeployMethod::Database)} disabled={is_deploying}>
                     { i18n.t("create_project.database_tab") }
                 </button>
             </div>
@@ -446,170 +1194,52 @@ pub fn create_project() -> Html
                         DeployMethod::GitHub => i18n.t("create_project.description_github"),
                         DeployMethod::Direct => i18n.t("create_project.description_direct"),
                         DeployMethod::Database => i18n.t("create_project.description_database"),
-                    }
+                    } 
                 }
                 </p>
 
+                if *active_method != DeployMethod::Database
                 {
-                    if *active_method != DeployMethod::Database 
-                    {
-                        html! 
-                        {
-                            <>
-                                <div class="form-group">
-                                    <label for="project_name">{ i18n.t("create_project.name_label") }</label>
-                                    <input type="text" id="project_name" class="text-input"
-                                        placeholder={i18n.t("create_project.name_placeholder")}
-                                        value={(*project_name).clone()}
-                                        onchange={handle_change(project_name.clone())}
-                                        required=true
-                                        disabled={is_deploying} />
-                                    <small style="color: var(--color-text-secondary)">{ i18n.t("create_project.name_help") }</small>
-                                </div>
-
-                                {
-                                    if *active_method == DeployMethod::GitHub 
-                                    {
-                                        html! 
-                                        {
-                                            <>
-                                                <div class="form-group">
-                                                    <label for="github_repo_url">{ i18n.t("create_project.github_repo_url_label") }</label>
-                                                    <input type="text" id="github_repo_url" class="text-input"
-                                                        placeholder={i18n.t("create_project.github_repo_url_placeholder")}
-                                                        value={(*github_repo_url).clone()}
-                                                        onchange={handle_change(github_repo_url.clone())}
-                                                        required=true
-                                                        disabled={is_deploying} />
-                                                </div>
-                                                <div class="form-group">
-                                                    <label for="github_branch">{ i18n.t("create_project.github_branch_label") }</label>
-                                                    <input type="text" id="github_branch" class="text-input"
-                                                        placeholder="main"
-                                                        value={(*github_branch).clone()}
-                                                        onchange={handle_change(github_branch.clone())}
-                                                        disabled={is_deploying} />
-                                                    <small style="color: var(--color-text-secondary)">{ i18n.t("create_project.github_branch_help") }</small>
-                                                </div>
-                                                <div class="form-group">
-                                                    <label for="github_root_dir">{ i18n.t("create_project.github_root_dir_label") }</label>
-                                                    <input type="text" id="github_root_dir" class="text-input"
-                                                        placeholder="/"
-                                                        value={(*github_root_dir).clone()}
-                                                        onchange={handle_change(github_root_dir.clone())}
-                                                        disabled={is_deploying} />
-                                                    <small style="color: var(--color-text-secondary)">{ i18n.t("create_project.github_root_dir_help") }</small>
-                                                </div>
-                                            </>
-                                        }
-                                    } 
-                                    else 
-                                    {
-                                        html! 
-                                        {
-                                            <>
-                                                <div class="form-group">
-                                                    <label for="image_url">{ i18n.t("create_project.image_label") }</label>
-                                                    <input type="text" id="image_url" class="text-input"
-                                                        placeholder={i18n.t("create_project.image_placeholder")}
-                                                        value={(*image_url).clone()}
-                                                        onchange={handle_change(image_url.clone())}
-                                                        required=true
-                                                        disabled={is_deploying} />
-                                                </div>
-
-                                                <div class="form-group">
-                                                    <label for="volume_path">{ i18n.t("create_project.volume_path_label") }</label>
-                                                    <input type="text" id="volume_path" class="text-input"
-                                                        placeholder="/data/uploads"
-                                                        value={(*volume_path_str).clone()}
-                                                        onchange={handle_change(volume_path_str.clone())}
-                                                        disabled={is_deploying} />
-                                                    <small style="color: var(--color-text-secondary)">
-                                                        { i18n.t("create_project.volume_path_help") }
-                                                    </small>
-                                                </div>
-                                            </>
-                                        }
-                                    }
-                                }
-
-                                <div class="form-group">
-                                    <label for="participants">{ i18n.t("create_project.participants_label") }</label>
-                                    <input type="text" id="participants" class="text-input"
-                                        placeholder={i18n.t("create_project.participants_placeholder")}
-                                        value={(*participants_str).clone()}
-                                        onchange={handle_change(participants_str.clone())}
-                                        disabled={is_deploying} />
-                                    <small style="color: var(--color-text-secondary)">{ i18n.t("create_project.participants_help") }</small>
-                                </div>
-
-                                <div class="form-group">
-                                    <label for="env_vars">{ i18n.t("create_project.env_vars_label") }</label>
-                                    <textarea id="env_vars" class="text-input"
-                                        placeholder="API_KEY=your_secret_key"
-                                        value={(*env_vars_str).clone()}
-                                        onchange={handle_change_textarea(env_vars_str.clone())}
-                                        rows="4"
-                                        disabled={is_deploying} />
-                                    <small style="color: var(--color-text-secondary)">{ i18n.t("create_project.env_vars_help") }</small>
-                                </div>
-
-                                <div class="form-group">
-                                    <label class="checkbox-label" for="create_db" style="display: flex; align-items: center; gap: var(--spacing-sm);">
-                                        <input type="checkbox" id="create_db"
-                                            checked={*create_db_with_project}
-                                            onchange={handle_checkbox_change}
-                                            disabled={is_deploying}
-                                        />
-                                        { i18n.t("create_project.create_db_checkbox") }
-                                    </label>
-                                </div>
-                            </>
-                        }
-                    } 
-                    else 
-                    {
-                        html!{}
-                    }
+                    { step_indicator }
+                    { step_body }
                 }
 
                 { if let Some(err) = &*error { render_error(err) } else { html! {} } }
 
+                if let Some(event) = &*deployment_event
                 {
-                    if let Some(stage) = &*deployment_stage 
+                    <DeploymentProgress
+                        project_id={event.project_id}
+                        timeline={deployment_timeline.clone()}
+                        context={ProgressContext::Creation}
+                    />
+                    if !build_log.lines.is_empty()
                     {
-                        html! 
-                        {
-                            <DeploymentProgress 
-                                stage={stage.clone()} 
-                                context={ProgressContext::Creation}
-                            />
-                        }
-                    }
-                    else
-                    {
-                        html! 
-                        {
-                            <button type="submit" class="button-primary" disabled={is_deploying}>
-                            {
-                                if is_deploying
-                                {
-                                    i18n.t("create_project.submit_button_loading")
-                                } 
-                                else if *active_method == DeployMethod::Database 
-                                {
-                                    i18n.t("database.create_button")
-                                } 
-                                else 
-                                {
-                                    i18n.t("create_project.submit_button")
-                                }
-                            }
-                            </button>
-                        }
-                    }
+                        <BuildConsole
+                            build_log={build_log.clone()}
+                            failed={matches!(event.stage, DeploymentStage::Failed { .. } | DeploymentStage::Cancelled)}
+                        />
+                    } 
                 }
+
+                <div class="wizard-actions">
+                    if *active_method != DeployMethod::Database && step.previous().is_some()
+                    {
+                        <button type="button" class="metrics-range-button" onclick={on_back} disabled={is_deploying}>
+                            { i18n.t("create_project.back_button") }
+                        </button>
+                    } 
+                    if *active_method != DeployMethod::Database && *step != WizardStep::Review
+                    {
+                        <button type="submit" class="button-primary">
+                            { i18n.t("create_project.next_button") }
+                        </button>
+                    } 
+                    else if can_submit
+                    {
+                        { submit_button }
+                    } 
+                </div>
             </form>
         </div>
     }
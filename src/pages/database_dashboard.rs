@@ -3,10 +3,11 @@ use yew_router::prelude::*;
 use i18nrs::yew::use_translation;
 use crate::
 {
+    contexts::config_context::use_config,
     models::database::DatabaseDetails,
     services::{database_service, project_service},
     router::AppRoute,
-    services::project_service::ApiError
+    services::api_client::ApiError
 };
 
 #[derive(Properties, PartialEq)]
@@ -20,6 +21,7 @@ pub fn database_dashboard(props: &DatabaseDashboardProps) -> Html
 {
     let (i18n, _) = use_translation();
     let navigator = use_navigator().unwrap();
+    let config = use_config();
     
     let db_details = use_state(|| None::<DatabaseDetails>);
     let projects = use_state(std::vec::Vec::new);
@@ -123,7 +125,7 @@ pub fn database_dashboard(props: &DatabaseDashboardProps) -> Html
 
                     <div style="margin-top: var(--spacing-lg);">
                         <a
-                            href="https://phpmyadmin.hangar.garageisep.com"
+                            href={config.phpmyadmin_url.clone()}
                             target="_blank"
                             rel="noopener noreferrer"
                             class="button-primary"
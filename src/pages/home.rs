@@ -1,6 +1,6 @@
 use crate::
 {
-    contexts::user_context::use_user,
+    contexts::{config_context::use_config, user_context::use_user},
     models::{database::DatabaseDetails, project::{Project, ProjectSourceType}},
     router::AppRoute,
     services::{database_service, project_service},
@@ -9,13 +9,12 @@ use i18nrs::yew::use_translation;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-const CAS_LOGIN_URL: &str = "https://portail-ovh.isep.fr/cas/login";
-
 #[function_component(Home)]
 pub fn home() -> Html 
 {
     let (i18n, _) = use_translation();
     let user_context = use_user();
+    let config = use_config();
 
     if user_context.user.is_some() 
     {
@@ -27,7 +26,7 @@ pub fn home() -> Html
             "{}/auth/callback",
             web_sys::window().unwrap().location().origin().unwrap()
         );
-        let login_url = format!("{}?service={}", CAS_LOGIN_URL, callback_url);
+        let login_url = format!("{}?service={}", config.cas_login_url, callback_url);
         html! 
         {
             <div class="home-page" style="text-align: center; margin-top: 10vh; display: flex; flex-direction: column; align-items: center; gap: var(--spacing-lg);">
@@ -63,7 +62,7 @@ fn dashboard() -> Html
                     Ok(projects) => owned_projects.set(Some(projects)),
                     Err(e) => 
                     {
-                        gloo_console::error!("Failed to fetch owned projects:", e);
+                        gloo_console::error!("Failed to fetch owned projects:", e.error_code);
                         owned_projects.set(Some(vec![]));
                     }
                 }
@@ -72,7 +71,7 @@ fn dashboard() -> Html
                     Ok(projects) => participating_projects.set(Some(projects)),
                     Err(e) => 
                     {
-                        gloo_console::error!("Failed to fetch participating projects:", e);
+                        gloo_console::error!("Failed to fetch participating projects:", e.error_code);
                         participating_projects.set(Some(vec![]));
                     }
                 }
@@ -100,9 +99,14 @@ fn dashboard() -> Html
         <div class="dashboard-home">
             <div class="dashboard-header">
                 <h1>{ welcome_message }</h1>
-                <Link<AppRoute> to={AppRoute::CreateProject} classes="button-primary">
-                    { i18n.t("dashboard.create_project_button") }
-                </Link<AppRoute>>
+                <div style="display: flex; gap: var(--spacing-sm); flex-wrap: wrap;">
+                    <Link<AppRoute> to={AppRoute::Templates} classes="button-primary">
+                        { i18n.t("dashboard.templates_button") }
+                    </Link<AppRoute>>
+                    <Link<AppRoute> to={AppRoute::CreateProject} classes="button-primary">
+                        { i18n.t("dashboard.create_project_button") }
+                    </Link<AppRoute>>
+                </div>
             </div>
             <p>{ i18n.t("dashboard.description") }</p>
 
@@ -8,4 +8,5 @@ pub mod admin;
 pub mod about;
 pub mod terms;
 pub mod privacy;
-pub mod contact;
\ No newline at end of file
+pub mod contact;
+pub mod templates;
\ No newline at end of file
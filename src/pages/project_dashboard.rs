@@ -3,17 +3,21 @@ use yew::prelude::*;
 use yew_router::prelude::*;
 
 use crate::components::system_notifications::SystemNotifications;
+use crate::contexts::alert_context::AlertProvider;
 use crate::contexts::sse_context::{use_sse_deployment, SseProvider};
 use crate::contexts::user_context::use_user;
 use crate::models::database::DatabaseDetails;
 use crate::models::project::ProjectDetails;
 use crate::router::AppRoute;
-use crate::services::project_service::ApiError;
+use crate::services::api_client::ApiError;
 use crate::services::sse_service::{ContainerStatus, DeploymentStage};
 use crate::services::{database_service, project_service};
 
 use crate::components::{
+    alert_banner::AlertBanner,
+    alert_rules::AlertRules,
     database_card::DatabaseCard,
+    deployment_history::DeploymentHistory,
     danger_zone::DangerZone,
     env_manager::EnvManager,
     image_update_form::ImageUpdateForm,
@@ -22,6 +26,8 @@ use crate::components::{
     project_info::ProjectInfo,
     project_logs::ProjectLogs,
     project_metrics::ProjectMetrics,
+    security_scan_card::SecurityScanCard,
+    sse_connection_badge::SseConnectionBadge,
 };
 
 const RELOAD_DELAY_MS: u32 = 1500;
@@ -35,9 +41,10 @@ pub struct ProjectDashboardProps
 #[function_component(ProjectDashboard)]
 pub fn project_dashboard(props: &ProjectDashboardProps) -> Html
 {
+    // Keyed so that switching projects starts over with empty logs, metrics and deployment state
     html!
     {
-        <SseProvider project_id={props.project_id}>
+        <SseProvider key={props.project_id} project_id={props.project_id}>
             <ProjectDashboardInner project_id={props.project_id} />
         </SseProvider>
     }
@@ -57,7 +64,7 @@ fn project_dashboard_inner(props: &ProjectDashboardInnerProps) -> Html
     
     let project_details = use_state(|| None::<ProjectDetails>);
     let my_database = use_state(|| None::<Option<DatabaseDetails>>);
-    let error = use_state(|| None::<String>);
+    let error = use_state(|| None::<ApiError>);
     let trigger_reload = use_state(|| 0_u32);
 
     // Détecter la fin d'un déploiement pour recharger les détails
@@ -124,7 +131,7 @@ fn project_dashboard_inner(props: &ProjectDashboardInnerProps) -> Html
     {
         let error_message = i18n
             .t("project_dashboard.load_error_message")
-            .replace("{error}", e);
+            .replace("{error}", &translate_error(e, &i18n));
         return html!
         {
             <div class="card error">
@@ -163,116 +170,87 @@ fn project_dashboard_inner(props: &ProjectDashboardInnerProps) -> Html
 
     html!
     {
-        <div>
-            <SystemNotifications />
+        <AlertProvider key={p.id} project_id={p.id} project_name={p.name.clone()}>
+            <div>
+                <SystemNotifications />
             
-            <h1>{ i18n.t("project_dashboard.title") }{ format!(": {}", p.name) }</h1>
+                <div style="display: flex; justify-content: space-between; align-items: center; flex-wrap: wrap; gap: var(--spacing-md);">
+                    <h1>{ i18n.t("project_dashboard.title") }{ format!(": {}", p.name) }</h1>
+                    <SseConnectionBadge />
+                </div>
 
-            <ProjectInfo project_details={details.clone()} />
+                <AlertBanner />
 
-            <DatabaseCard
-                project_details={details.clone()}
-                my_database={my_db_option.clone()}
-                has_control_access={has_strong_access}
-                on_update={on_update.clone()}
-            />
+                <ProjectInfo project_details={details.clone()} />
 
-            if has_weak_access
-            {
-                <ProjectControls
-                    project_id={p.id}
+                <DatabaseCard
+                    project_details={details.clone()}
+                    my_database={my_db_option.clone()}
+                    has_control_access={has_strong_access}
                     on_update={on_update.clone()}
                 />
-            }
 
-            <ProjectLogs project_id={p.id} />
+                if has_weak_access
+                {
+                    <ProjectControls
+                        project_id={p.id}
+                        on_update={on_update.clone()}
+                    />
+                }
 
-            <ProjectMetrics />
+                <ProjectLogs project_id={p.id} project_name={p.name.clone()} />
 
-            if has_strong_access
-            {
-                <ParticipantManager
-                    project_id={p.id}
-                    participants={details.participants.clone()}
-                    on_update={on_update.clone()}
-                />
-            }
+                <ProjectMetrics project_name={p.name.clone()} />
 
-            if has_weak_access
-            {
-                <EnvManager
-                    project_id={p.id}
-                    current_env_vars={p.env_vars.clone()}
-                    on_update={on_update.clone()}
-                />
+                <AlertRules />
 
-                <ImageUpdateForm
-                    project_id={p.id}
-                    project_name={p.name.clone()}
-                    source_type={p.source.clone()}
-                    on_update={on_update.clone()}
-                />
-            }
+                if has_strong_access
+                {
+                    <ParticipantManager
+                        project_id={p.id}
+                        participants={details.participants.clone()}
+                        on_update={on_update.clone()}
+                    />
+                }
 
-            if has_strong_access
-            {
-                <DangerZone
+                if has_weak_access
+                {
+                    <EnvManager
+                        project_id={p.id}
+                        current_env_vars={p.env_vars.clone()}
+                        on_update={on_update.clone()}
+                    />
+
+                    <ImageUpdateForm
+                        project_id={p.id}
+                        project_name={p.name.clone()}
+                        source_type={p.source.clone()}
+                        source_branch={p.source_branch.clone()}
+                        auto_deploy={p.auto_deploy}
+                        on_update={on_update.clone()}
+                    />
+                }
+
+                <SecurityScanCard project_id={p.id} />
+
+                <DeploymentHistory
                     project_id={p.id}
                     project_name={p.name.clone()}
-                    has_linked_database={details.database.is_some()}
+                    can_rollback={has_weak_access}
+                    on_update={on_update.clone()}
+                    reload={*trigger_reload}
                 />
-            }
-        </div>
-    }
-}
 
-
-pub fn render_log_line(line: &str) -> Html 
-{
-    let (timestamp, message) = parse_log_line(line);
-    let log_level_class = determine_log_level(message);
-
-    html! 
-    {
-        <div class="log-line">
-            <span class="log-timestamp">{ format_timestamp(timestamp) }</span>
-            <span class={classes!("log-message", log_level_class)}>{ message }</span>
-        </div>
-    }
-}
-
-fn parse_log_line(line: &str) -> (&str, &str) 
-{
-    let parts: Vec<&str> = line.splitn(2, ' ').collect();
-    if parts.len() == 2 && parts[0].ends_with('Z') 
-    {
-        (parts[0], parts[1].trim())
-    } 
-    else 
-    {
-        ("", line)
-    }
-}
-
-fn format_timestamp(timestamp: &str) -> &str 
-{
-    timestamp.split('.').next().unwrap_or(timestamp)
-}
-
-fn determine_log_level(message: &str) -> &'static str 
-{
-    let message_upper = message.to_uppercase();
-    if message_upper.contains("ERROR") || message_upper.contains("FAILED") 
-    {
-        "log-error"
-    } 
-    else if message_upper.contains("WARN") || message_upper.contains("WARNING") 
-    {
-        "log-warn"
-    } 
-    else 
-    {
-        "log-info"
+                if has_strong_access
+                {
+                    <DangerZone
+                        project_id={p.id}
+                        project_name={p.name.clone()}
+                        has_linked_database={details.database.is_some()}
+                    />
+                }
+            </div>
+        </AlertProvider>
     }
 }
 
@@ -325,4 +303,4 @@ pub fn get_status_class(status: &ContainerStatus) -> &'static str
         ContainerStatus::Removing => "status_removing",
         ContainerStatus::Unknown => "status_unknown",
     }
-}
+}
\ No newline at end of file
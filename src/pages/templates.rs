@@ -0,0 +1,94 @@
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::contexts::config_context::use_config;
+use crate::models::template::{ProjectTemplate, TEMPLATES};
+use crate::router::AppRoute;
+
+#[function_component(Templates)]
+pub fn templates() -> Html
+{
+    let (i18n, _) = use_translation();
+    let config = use_config();
+    let navigator = use_navigator().unwrap();
+
+    let on_use = |template: &'static ProjectTemplate|
+    {
+        let navigator = navigator.clone();
+        Callback::from(move |_|
+        {
+            if let Err(e) = navigator.push_with_query(&AppRoute::CreateProject, &[("template", template.id)])
+            {
+                gloo_console::error!("Failed to open template:", e.to_string());
+            }
+        })
+    };
+
+    let template_card = |template: &'static ProjectTemplate|
+    {
+        let repo_url = config.template_repo_url(template.starter_repo);
+        let mut env_keys: Vec<&str> = template.env_vars.iter().map(|(k, _)| *k).collect();
+        env_keys.sort();
+
+        html!
+        {
+            <div class="card template-card">
+                <h3>{ i18n.t(&format!("templates.{}_name", template.id)) }</h3>
+                <div class="template-stack">
+                    { for template.stack.iter().map(|tech| html! { <span class="template-tag">{ *tech }</span> }) }
+                </div>
+                <p>{ i18n.t(&format!("templates.{}_description", template.id)) }</p>
+
+                <ul class="template-settings">
+                    <li>
+                        <span>{ i18n.t("templates.starter_repo") }</span>
+                        <a href={repo_url} target="_blank" rel="noopener noreferrer" class="detail-value">{ template.starter_repo }</a>
+                    </li>
+                    if !env_keys.is_empty()
+                    {
+                        <li>
+                            <span>{ i18n.t("templates.env_vars") }</span>
+                            <code>{ env_keys.join(", ") }</code>
+                        </li>
+                    }
+                    if let Some(path) = template.persistent_volume_path
+                    {
+                        <li>
+                            <span>{ i18n.t("templates.volume") }</span>
+                            <code>{ path }</code>
+                        </li>
+                    }
+                    if template.create_database
+                    {
+                        <li>
+                            <span>{ i18n.t("templates.database") }</span>
+                            <span>{ "MySQL" }</span>
+                        </li>
+                    }
+                </ul>
+
+                <button class="button-primary" onclick={on_use(template)}>
+                    { i18n.t("templates.use_button") }
+                </button>
+            </div>
+        }
+    };
+
+    html!
+    {
+        <div class="templates-page">
+            <div class="dashboard-header">
+                <h1>{ i18n.t("templates.title") }</h1>
+                <Link<AppRoute> to={AppRoute::CreateProject} classes="button-primary">
+                    { i18n.t("templates.start_from_scratch") }
+                </Link<AppRoute>>
+            </div>
+            <p>{ i18n.t("templates.description") }</p>
+
+            <div class="project-grid">
+                { for TEMPLATES.iter().map(template_card) }
+            </div>
+        </div>
+    }
+}
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{components::protected_route::{AdminRoute, ProtectedRoute}, pages::{self, admin, create_project, database_dashboard, project_dashboard}};
+use crate::{components::protected_route::{AdminRoute, ProtectedRoute}, pages::{self, admin, create_project, database_dashboard, project_dashboard, templates}};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -11,6 +11,8 @@ pub enum AppRoute
     AuthCallback,
     #[at("/projects/create")]
     CreateProject,
+    #[at("/templates")]
+    Templates,
     #[at("/projects/:id")]
     ProjectDashboard { id: i32 },
     #[at("/databases/:id")]
@@ -42,6 +44,12 @@ pub fn switch(route: AppRoute) -> Html
                 <create_project::CreateProject />
             </ProtectedRoute>
         },
+        AppRoute::Templates => html!
+        {
+            <ProtectedRoute>
+                <templates::Templates />
+            </ProtectedRoute>
+        },
         AppRoute::ProjectDashboard { id } => html! 
         {
             <ProtectedRoute>
@@ -0,0 +1,60 @@
+use gloo_storage::{LocalStorage, Storage};
+use web_sys::{Notification, NotificationOptions, NotificationPermission};
+
+use crate::models::alert::AlertRule;
+
+const STORAGE_KEY_PREFIX: &str = "hangar.alert_rules.";
+
+fn storage_key(project_id: i32) -> String
+{
+    format!("{}{}", STORAGE_KEY_PREFIX, project_id)
+}
+
+/// Alert rules are kept per browser rather than on the backend.
+pub fn load_rules(project_id: i32) -> Vec<AlertRule>
+{
+    LocalStorage::get(storage_key(project_id)).unwrap_or_default()
+}
+
+pub fn save_rules(project_id: i32, rules: &[AlertRule])
+{
+    if let Err(e) = LocalStorage::set(storage_key(project_id), rules)
+    {
+        gloo_console::warn!("Failed to save alert rules:", e.to_string());
+    }
+}
+
+/// iOS Safari and some webviews have no Notification API at all, and calling into it throws.
+/// Alerts are then only shown by the in-page banner.
+pub fn notifications_supported() -> bool
+{
+    web_sys::window().is_some_and(|window| js_sys::Reflect::has(&window, &"Notification".into()).unwrap_or(false))
+}
+
+/// Asks for permission to show notifications, if the user has not answered yet.
+/// Browsers only show the prompt when this runs from a user gesture.
+pub fn request_notification_permission()
+{
+    if notifications_supported()
+        && Notification::permission() == NotificationPermission::Default
+        && let Err(e) = Notification::request_permission()
+    {
+        gloo_console::warn!("Notification permission request failed:", e);
+    }
+}
+
+pub fn notify(title: &str, body: &str)
+{
+    if !notifications_supported() || Notification::permission() != NotificationPermission::Granted
+    {
+        return;
+    }
+
+    let options = NotificationOptions::new();
+    options.set_body(body);
+
+    if let Err(e) = Notification::new_with_options(title, &options)
+    {
+        gloo_console::warn!("Failed to show notification:", e);
+    }
+}
\ No newline at end of file
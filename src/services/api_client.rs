@@ -0,0 +1,198 @@
+use std::cell::RefCell;
+
+use gloo_net::http::{Method, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::models::config::AppConfig;
+
+thread_local!
+{
+    static API_ROOT: RefCell<String> = RefCell::new(AppConfig::default().api_root);
+}
+
+/// Points every `ApiClient::default()` at `root`. Called once `/config.json` has been loaded.
+pub fn set_api_root(root: &str)
+{
+    API_ROOT.with(|r| *r.borrow_mut() = root.to_string());
+}
+
+#[derive(Clone, Deserialize, PartialEq, Debug)]
+pub struct ApiError
+{
+    pub error_code: String,
+    pub details: Option<String>,
+}
+
+impl ApiError
+{
+    pub fn new(error_code: &str) -> Self
+    {
+        ApiError
+        {
+            error_code: error_code.to_string(),
+            details: None,
+        }
+    }
+
+    pub fn with_details(error_code: &str, details: impl ToString) -> Self
+    {
+        ApiError
+        {
+            error_code: error_code.to_string(),
+            details: Some(details.to_string()),
+        }
+    }
+}
+
+pub async fn parse_detailed_error_response(response: Response) -> ApiError
+{
+    if let Ok(error_body) = response.json::<ApiError>().await
+    {
+        error_body
+    }
+    else if response.status() == 404
+    {
+        ApiError::new("NOT_FOUND")
+    }
+    else
+    {
+        ApiError::new(&format!("HTTP_ERROR_{}", response.status()))
+    }
+}
+
+/// Entry point for every call to the Hangar backend.
+///
+/// All helpers resolve to `Result<_, ApiError>`: network failures become `NETWORK_ERROR`,
+/// non-2xx responses go through `parse_detailed_error_response` and undecodable bodies
+/// become `RESPONSE_PARSE_ERROR`, so components can always render them with `translate_error`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ApiClient
+{
+    root: String,
+}
+
+impl Default for ApiClient
+{
+    fn default() -> Self
+    {
+        API_ROOT.with(|root| ApiClient::new(&root.borrow()))
+    }
+}
+
+impl ApiClient
+{
+    pub fn new(root: &str) -> Self
+    {
+        ApiClient
+        {
+            root: root.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn url(&self, path: &str) -> String
+    {
+        format!("{}{}", self.root, path)
+    }
+
+    pub fn get(&self, path: &str) -> ApiRequest
+    {
+        self.request(Method::GET, path)
+    }
+
+    pub fn post(&self, path: &str) -> ApiRequest
+    {
+        self.request(Method::POST, path)
+    }
+
+    pub fn put(&self, path: &str) -> ApiRequest
+    {
+        self.request(Method::PUT, path)
+    }
+
+    pub fn delete(&self, path: &str) -> ApiRequest
+    {
+        self.request(Method::DELETE, path)
+    }
+
+    fn request(&self, method: Method, path: &str) -> ApiRequest
+    {
+        ApiRequest
+        {
+            builder: RequestBuilder::new(&self.url(path)).method(method),
+            body: None,
+        }
+    }
+}
+
+pub struct ApiRequest
+{
+    builder: RequestBuilder,
+    body: Option<Result<String, ApiError>>,
+}
+
+impl ApiRequest
+{
+    /// Attaches `payload` as a JSON body.
+    pub fn body<B: Serialize + ?Sized>(mut self, payload: &B) -> Self
+    {
+        self.body = Some(
+            serde_json::to_string(payload)
+                .map_err(|e| ApiError::with_details("CLIENT_ERROR", e))
+        );
+        self
+    }
+
+    pub fn query<'a>(mut self, params: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self
+    {
+        self.builder = self.builder.query(params);
+        self
+    }
+
+    /// Sends the request and decodes the JSON response into `T`.
+    /// An empty body (e.g. `204 No Content`) is decoded as `null`, so `T` may be `()` or an `Option`.
+    pub async fn json<T: DeserializeOwned>(self) -> Result<T, ApiError>
+    {
+        let response = self.execute().await?;
+
+        let text = response
+            .text()
+            .await
+            .map_err(|e| ApiError::with_details("RESPONSE_PARSE_ERROR", e))?;
+
+        let text = if text.trim().is_empty() { "null" } else { text.as_str() };
+
+        serde_json::from_str::<T>(text)
+            .map_err(|e| ApiError::with_details("RESPONSE_PARSE_ERROR", e))
+    }
+
+    /// Sends the request and ignores whatever body the backend answers with.
+    pub async fn send(self) -> Result<(), ApiError>
+    {
+        self.execute().await.map(|_| ())
+    }
+
+    async fn execute(self) -> Result<Response, ApiError>
+    {
+        let request = match self.body
+        {
+            Some(body) => self.builder
+                .header("Content-Type", "application/json")
+                .body(body?),
+            None => self.builder.build(),
+        }
+        .map_err(|e| ApiError::with_details("CLIENT_ERROR", e))?;
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| ApiError::with_details("NETWORK_ERROR", e))?;
+
+        if !response.ok()
+        {
+            return Err(parse_detailed_error_response(response).await);
+        }
+
+        Ok(response)
+    }
+}
\ No newline at end of file
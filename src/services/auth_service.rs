@@ -1,9 +1,7 @@
 use crate::models::user::User;
-use gloo_net::http::Request;
+use crate::services::api_client::{ApiClient, ApiError};
 use serde::Deserialize;
 
-const API_ROOT: &str = "/api";
-
 #[derive(Deserialize)]
 struct AuthResponse 
 {
@@ -16,46 +14,31 @@ struct MeResponse
     user: User,
 }
 
-pub async fn validate_ticket(ticket: &str) -> Result<User, String>
+pub async fn validate_ticket(ticket: &str) -> Result<User, ApiError>
 {
-    let url = format!("{}/auth/callback?ticket={}", API_ROOT, ticket);
-    
-    let response = Request::get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(format!("API Error: status {}", response.status()));
-    }
-
-    response
+    ApiClient::default()
+        .get("/auth/callback")
+        .query([("ticket", ticket)])
         .json::<AuthResponse>()
         .await
         .map(|r| r.user)
-        .map_err(|e| format!("Deserialization error: {}", e))
 }
 
 
-pub async fn get_current_user() -> Result<User, String> 
+pub async fn get_current_user() -> Result<User, ApiError> 
 {
-    Request::get(&format!("{}/auth/me", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?
+    ApiClient::default()
+        .get("/auth/me")
         .json::<MeResponse>()
         .await
         .map(|r| r.user)
-        .map_err(|_| "Not authenticated or session expired".to_string())
 }
 
 
-pub async fn logout() -> Result<(), String> 
+pub async fn logout() -> Result<(), ApiError> 
 {
-    Request::get(&format!("{}/auth/logout", API_ROOT))
+    ApiClient::default()
+        .get("/auth/logout")
         .send()
         .await
-        .map_err(|e| format!("Network error: {}", e))?;
-    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,12 @@
+use crate::models::config::AppConfig;
+use crate::services::api_client::{ApiClient, ApiError};
+
+const CONFIG_PATH: &str = "/config.json";
+
+pub async fn fetch_config() -> Result<AppConfig, ApiError>
+{
+    ApiClient::new("")
+        .get(CONFIG_PATH)
+        .json::<AppConfig>()
+        .await
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use gloo_storage::{LocalStorage, Storage};
+
+use crate::models::draft::CreateProjectDraft;
+
+const DRAFT_STORAGE_KEY: &str = "hangar.create_project_draft";
+/// Older drafts are dropped rather than resurfacing long-forgotten forms.
+const DRAFT_MAX_AGE_MS: f64 = 7.0 * 24.0 * 60.0 * 60.0 * 1000.0;
+
+pub fn load_draft() -> Option<CreateProjectDraft>
+{
+    let draft: CreateProjectDraft = LocalStorage::get(DRAFT_STORAGE_KEY).ok()?;
+
+    if js_sys::Date::now() - draft.saved_at > DRAFT_MAX_AGE_MS || draft.is_empty()
+    {
+        clear_draft();
+        return None;
+    }
+
+    Some(draft)
+}
+
+/// Saves the draft without env var values. An empty form removes any saved draft instead.
+pub fn save_draft(draft: &CreateProjectDraft)
+{
+    if draft.is_empty()
+    {
+        clear_draft();
+        return;
+    }
+
+    let mut draft = draft.clone().without_secrets();
+    draft.saved_at = js_sys::Date::now();
+
+    if let Err(e) = LocalStorage::set(DRAFT_STORAGE_KEY, &draft)
+    {
+        gloo_console::warn!("Failed to save project draft:", e.to_string());
+    }
+}
+
+pub fn clear_draft()
+{
+    LocalStorage::delete(DRAFT_STORAGE_KEY);
+}
\ No newline at end of file
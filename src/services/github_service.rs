@@ -0,0 +1,33 @@
+use crate::models::github::{GithubBranchesResponse, GithubDirectoriesResponse, GithubRepositoriesResponse, GithubRepository};
+use crate::services::api_client::{ApiClient, ApiError};
+
+/// Repositories visible to the user's installation of the Hangar GitHub App.
+pub async fn list_repositories() -> Result<Vec<GithubRepository>, ApiError>
+{
+    ApiClient::default()
+        .get("/github/repositories")
+        .json::<GithubRepositoriesResponse>()
+        .await
+        .map(|r| r.repositories)
+}
+
+/// `full_name` is the `owner/name` of the repository.
+pub async fn list_branches(full_name: &str) -> Result<Vec<String>, ApiError>
+{
+    ApiClient::default()
+        .get(&format!("/github/repositories/{}/branches", full_name))
+        .json::<GithubBranchesResponse>()
+        .await
+        .map(|r| r.branches)
+}
+
+/// Top-level directories of `branch`, without a leading slash.
+pub async fn list_directories(full_name: &str, branch: &str) -> Result<Vec<String>, ApiError>
+{
+    ApiClient::default()
+        .get(&format!("/github/repositories/{}/directories", full_name))
+        .query([("branch", branch)])
+        .json::<GithubDirectoriesResponse>()
+        .await
+        .map(|r| r.directories)
+}
\ No newline at end of file
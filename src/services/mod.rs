@@ -1,4 +1,9 @@
+pub mod api_client;
 pub mod auth_service;
+pub mod config_service;
 pub mod project_service;
 pub mod database_service;
-pub mod sse_service;
\ No newline at end of file
+pub mod sse_service;
+pub mod alert_service;
+pub mod github_service;
+pub mod draft_service;
\ No newline at end of file
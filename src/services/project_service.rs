@@ -1,18 +1,13 @@
 use crate::models::database::{AdminDatabaseInfo, AdminDatabasesResponse};
+use crate::models::deployment::{Deployment, DeploymentsResponse, WebhookDelivery, WebhookDeliveryResponse};
+use crate::models::scan::{ScanReport, ScanReportResponse};
 use crate::models::project::{
-    DeployPayload, DownProjectInfo, DownProjectsResponse, GlobalMetrics, Project, ProjectDetails, ProjectDetailsResponse, ProjectsResponse, UpdateEnvPayload
+    DeployPayload, DownProjectInfo, DownProjectsResponse, GlobalMetrics, MetricsHistoryResponse, MetricsSample, Project, ProjectDetails,
+    ProjectDetailsResponse, ProjectResourceUsage, ProjectResourceUsageResponse, ProjectsResponse, UpdateEnvPayload
 };
-use gloo_net::http::Request;
+use crate::services::api_client::{ApiClient, ApiError};
 use serde::{Deserialize, Serialize};
 
-const API_ROOT: &str = "/api";
-
-#[derive(Clone, Deserialize, PartialEq, Debug)]
-pub struct ApiError 
-{
-    pub error_code: String,
-    pub details: Option<String>,
-}
 #[derive(Deserialize)]
 pub struct LogsResponse 
 {
@@ -26,210 +21,146 @@ struct UpdateImagePayload
 }
 
 #[derive(Serialize)]
-struct ParticipantPayload 
+struct AutoDeployPayload
 {
-    participant_id: String,
+    enabled: bool,
 }
 
-async fn parse_simple_error_response(response: gloo_net::http::Response) -> String 
+#[derive(Serialize)]
+struct RollbackPayload
 {
-    #[derive(Deserialize)]
-    struct SimpleErrorResponse 
-    {
-        error_code: String,
-    }
-
-    if let Ok(error_body) = response.json::<SimpleErrorResponse>().await 
-    {
-        error_body.error_code
-    } 
-    else 
-    {
-        format!("HTTP_ERROR_{}", response.status())
-    }
+    deployment_id: i32,
 }
 
-pub async fn parse_detailed_error_response(response: gloo_net::http::Response) -> ApiError 
+#[derive(Serialize)]
+struct ParticipantPayload 
 {
-    if let Ok(error_body) = response.json::<ApiError>().await 
-    {
-        error_body
-    } 
-    else 
-    {
-        ApiError 
-        {
-            error_code: format!("HTTP_ERROR_{}", response.status()),
-            details: None,
-        }
-    }
+    participant_id: String,
 }
 
-pub async fn get_owned_projects() -> Result<Vec<Project>, String> 
+pub async fn get_owned_projects() -> Result<Vec<Project>, ApiError> 
 {
-    let response = Request::get(&format!("{}/projects/owned", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-
-    response
+    ApiClient::default()
+        .get("/projects/owned")
         .json::<ProjectsResponse>()
         .await
         .map(|r| r.projects)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
-pub async fn get_participating_projects() -> Result<Vec<Project>, String> 
+pub async fn get_participating_projects() -> Result<Vec<Project>, ApiError> 
 {
-    let response = Request::get(&format!("{}/projects/participations", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-
-    response
+    ApiClient::default()
+        .get("/projects/participations")
         .json::<ProjectsResponse>()
         .await
         .map(|r| r.projects)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
 pub async fn deploy_project(payload: DeployPayload) -> Result<ProjectDetails, ApiError> 
 {
-    let response = Request::post(&format!("{}/projects/deploy", API_ROOT))
-        .json(&payload)
-        .map_err(|_| ApiError 
-        {
-            error_code: "CLIENT_SERIALIZATION_ERROR".to_string(),
-            details: None,
-        })?
-        .send()
-        .await
-        .map_err(|e| ApiError 
-        {
-            error_code: "NETWORK_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })?;
-
-    if !response.ok() 
-    {
-        return Err(parse_detailed_error_response(response).await);
-    }
-
-    response
+    ApiClient::default()
+        .post("/projects/deploy")
+        .body(&payload)
         .json::<ProjectDetailsResponse>()
         .await
         .map(|pr| pr.project)
-        .map_err(|e| ApiError 
-        {
-            error_code: "RESPONSE_PARSE_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })
 }
 
-pub async fn purge_project(project_id: i32) -> Result<(), String> 
+pub async fn purge_project(project_id: i32) -> Result<(), ApiError> 
 {
-    let response = Request::delete(&format!("{}/projects/{}", API_ROOT, project_id))
+    ApiClient::default()
+        .delete(&format!("/projects/{}", project_id))
         .send()
         .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-
-    Ok(())
 }
 
-pub async fn get_project_details(project_id: i32) -> Result<ProjectDetails, String> 
+pub async fn get_project_details(project_id: i32) -> Result<ProjectDetails, ApiError> 
 {
-    let response = Request::get(&format!("{}/projects/{}", API_ROOT, project_id))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-
-    response
+    ApiClient::default()
+        .get(&format!("/projects/{}", project_id))
         .json::<ProjectDetailsResponse>()
         .await
         .map(|r| r.project)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
 
-pub async fn start_project(project_id: i32) -> Result<(), String> 
+pub async fn start_project(project_id: i32) -> Result<(), ApiError> 
 {
-    let response = Request::post(&format!("{}/projects/{}/start", API_ROOT, project_id))
+    ApiClient::default()
+        .post(&format!("/projects/{}/start", project_id))
         .send()
         .await
-        .map_err(|e| format!("Network error: {}", e))?;
+}
 
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-    Ok(())
+pub async fn stop_project(project_id: i32) -> Result<(), ApiError> 
+{
+    ApiClient::default()
+        .post(&format!("/projects/{}/stop", project_id))
+        .send()
+        .await
 }
 
-pub async fn stop_project(project_id: i32) -> Result<(), String> 
+pub async fn restart_project(project_id: i32) -> Result<(), ApiError> 
 {
-    let response = Request::post(&format!("{}/projects/{}/stop", API_ROOT, project_id))
+    ApiClient::default()
+        .post(&format!("/projects/{}/restart", project_id))
         .send()
         .await
-        .map_err(|e| format!("Network error: {}", e))?;
+}
 
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-    Ok(())
+pub async fn get_project_logs(project_id: i32) -> Result<String, ApiError> 
+{
+    ApiClient::default()
+        .get(&format!("/projects/{}/logs", project_id))
+        .json::<LogsResponse>()
+        .await
+        .map(|r| r.logs)
 }
 
-pub async fn restart_project(project_id: i32) -> Result<(), String> 
+/// Samples recorded by the backend over the last `minutes`, oldest first.
+pub async fn get_project_metrics_history(project_id: i32, minutes: u32) -> Result<Vec<MetricsSample>, ApiError>
 {
-    let response = Request::post(&format!("{}/projects/{}/restart", API_ROOT, project_id))
-        .send()
+    ApiClient::default()
+        .get(&format!("/projects/{}/metrics/history", project_id))
+        .query([("minutes", minutes.to_string().as_str())])
+        .json::<MetricsHistoryResponse>()
         .await
-        .map_err(|e| format!("Network error: {}", e))?;
+        .map(|r| r.samples)
+}
 
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-    Ok(())
+/// Past and running deployments of the project, most recent first.
+pub async fn get_project_deployments(project_id: i32) -> Result<Vec<Deployment>, ApiError>
+{
+    ApiClient::default()
+        .get(&format!("/projects/{}/deployments", project_id))
+        .json::<DeploymentsResponse>()
+        .await
+        .map(|r| r.deployments)
 }
 
-pub async fn get_project_logs(project_id: i32) -> Result<String, String> 
+/// Vulnerability scan of the currently deployed image.
+pub async fn get_project_scan_report(project_id: i32) -> Result<ScanReport, ApiError>
 {
-    let response = Request::get(&format!("{}/projects/{}/logs", API_ROOT, project_id))
-        .send()
+    ApiClient::default()
+        .get(&format!("/projects/{}/scan", project_id))
+        .json::<ScanReportResponse>()
         .await
-        .map_err(|e| format!("Network error: {}", e))?;
+        .map(|r| r.report)
+}
 
-    if !response.ok() 
+/// Redeploys the image digest and environment variables of a past deployment.
+pub async fn rollback_project(project_id: i32, deployment_id: i32) -> Result<(), ApiError>
+{
+    let payload = RollbackPayload
     {
-        return Err(parse_simple_error_response(response).await);
-    }
+        deployment_id,
+    };
 
-    response
-        .json::<LogsResponse>()
+    ApiClient::default()
+        .post(&format!("/projects/{}/rollback", project_id))
+        .body(&payload)
+        .send()
         .await
-        .map(|r| r.logs)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
 pub async fn update_project_image(project_id: i32, new_image_url: &str) -> Result<(), ApiError> 
@@ -239,175 +170,125 @@ pub async fn update_project_image(project_id: i32, new_image_url: &str) -> Resul
         new_image_url: new_image_url.to_string(),
     };
 
-    let response = Request::put(&format!("{}/projects/{}/image", API_ROOT, project_id))
-        .json(&payload)
-        .map_err(|_| ApiError 
-        {
-            error_code: "CLIENT_SERIALIZATION_ERROR".to_string(),
-            details: None,
-        })?
+    ApiClient::default()
+        .put(&format!("/projects/{}/image", project_id))
+        .body(&payload)
         .send()
         .await
-        .map_err(|e| ApiError 
-        {
-            error_code: "NETWORK_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })?;
-
-    if !response.ok() 
-    {
-        return Err(parse_detailed_error_response(response).await);
-    }
-
-    Ok(())
 }
 
 pub async fn rebuild_project(project_id: i32) -> Result<(), ApiError>
 {
-    let response = Request::put(&format!("{}/projects/{}/rebuild", API_ROOT, project_id))
+    ApiClient::default()
+        .put(&format!("/projects/{}/rebuild", project_id))
         .send()
         .await
-        .map_err(|e| ApiError 
-        {
-            error_code: "NETWORK_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })?;
-
-    if !response.ok()
-    {
-        return Err(parse_detailed_error_response(response).await);
-    }
+}
 
-    Ok(())
+/// Aborts the deployment in progress, the backend then reports `DeploymentStage::Cancelled`.
+pub async fn cancel_deployment(project_id: i32) -> Result<(), ApiError>
+{
+    ApiClient::default()
+        .post(&format!("/projects/{}/deployment/cancel", project_id))
+        .send()
+        .await
 }
 
-pub async fn add_participant(project_id: i32, participant_id: &str) -> Result<(), ApiError> 
+pub async fn set_auto_deploy(project_id: i32, enabled: bool) -> Result<(), ApiError>
 {
-    let payload = ParticipantPayload 
+    let payload = AutoDeployPayload
     {
-        participant_id: participant_id.to_string(),
+        enabled,
     };
-    let response = Request::post(&format!("{}/projects/{}/participants", API_ROOT, project_id))
-        .json(&payload)
-        .map_err(|_| ApiError 
-        {
-            error_code: "CLIENT_ERROR".to_string(),
-            details: None,
-        })?
+
+    ApiClient::default()
+        .put(&format!("/projects/{}/auto-deploy", project_id))
+        .body(&payload)
         .send()
         .await
-        .map_err(|e| ApiError 
-        {
-            error_code: "NETWORK_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })?;
+}
 
-    if !response.ok() 
-    {
-        return Err(parse_detailed_error_response(response).await);
-    }
-    Ok(())
+/// Latest push webhook received for a GitHub project, `None` if GitHub never called it.
+pub async fn get_last_webhook_delivery(project_id: i32) -> Result<Option<WebhookDelivery>, ApiError>
+{
+    ApiClient::default()
+        .get(&format!("/projects/{}/webhook/last-delivery", project_id))
+        .json::<WebhookDeliveryResponse>()
+        .await
+        .map(|r| r.delivery)
 }
 
-pub async fn remove_participant(project_id: i32, participant_id: &str) -> Result<(), String> 
+pub async fn add_participant(project_id: i32, participant_id: &str) -> Result<(), ApiError> 
 {
-    let response = Request::delete(&format!(
-        "{}/projects/{}/participants/{}",
-        API_ROOT, project_id, participant_id
-    ))
-    .send()
-    .await
-    .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
+    let payload = ParticipantPayload 
     {
-        return Err(parse_simple_error_response(response).await);
-    }
-    Ok(())
+        participant_id: participant_id.to_string(),
+    };
+
+    ApiClient::default()
+        .post(&format!("/projects/{}/participants", project_id))
+        .body(&payload)
+        .send()
+        .await
 }
 
-pub async fn get_all_projects_admin() -> Result<Vec<Project>, String> 
+pub async fn remove_participant(project_id: i32, participant_id: &str) -> Result<(), ApiError> 
 {
-    let response = Request::get(&format!("{}/admin/projects", API_ROOT))
+    ApiClient::default()
+        .delete(&format!("/projects/{}/participants/{}", project_id, participant_id))
         .send()
         .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
+}
 
-    response
+pub async fn get_all_projects_admin() -> Result<Vec<Project>, ApiError> 
+{
+    ApiClient::default()
+        .get("/admin/projects")
         .json::<ProjectsResponse>()
         .await
         .map(|r| r.projects)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
-pub async fn get_all_databases_admin() -> Result<Vec<AdminDatabaseInfo>, String>
+pub async fn get_all_databases_admin() -> Result<Vec<AdminDatabaseInfo>, ApiError>
 {
-    let response = Request::get(&format!("{}/admin/databases", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok()
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-
-    response
+    ApiClient::default()
+        .get("/admin/databases")
         .json::<AdminDatabasesResponse>()
         .await
         .map(|r| r.databases)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
-pub async fn get_global_metrics_admin() -> Result<GlobalMetrics, String>
+pub async fn get_global_metrics_admin() -> Result<GlobalMetrics, ApiError>
 {
-    Request::get(&format!("{}/admin/metrics", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?
+    ApiClient::default()
+        .get("/admin/metrics")
         .json::<GlobalMetrics>()
         .await
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
-pub async fn get_down_projects_admin() -> Result<Vec<DownProjectInfo>, String> 
+pub async fn get_projects_resource_usage_admin() -> Result<Vec<ProjectResourceUsage>, ApiError>
 {
-    Request::get(&format!("{}/admin/projects/down", API_ROOT))
-        .send()
+    ApiClient::default()
+        .get("/admin/metrics/projects")
+        .json::<ProjectResourceUsageResponse>()
         .await
-        .map_err(|e| format!("Network error: {}", e))?
+        .map(|r| r.projects)
+}
+
+pub async fn get_down_projects_admin() -> Result<Vec<DownProjectInfo>, ApiError> 
+{
+    ApiClient::default()
+        .get("/admin/projects/down")
         .json::<DownProjectsResponse>()
         .await
         .map(|r| r.down_projects)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
 pub async fn update_env_vars(project_id: i32, payload: &UpdateEnvPayload) -> Result<(), ApiError>
 {
-    let response = Request::put(&format!("{}/projects/{}/env", API_ROOT, project_id))
-        .json(payload)
-        .map_err(|_| ApiError 
-        {
-            error_code: "CLIENT_ERROR".to_string(),
-            details: None,
-        })?
+    ApiClient::default()
+        .put(&format!("/projects/{}/env", project_id))
+        .body(payload)
         .send()
         .await
-        .map_err(|e| ApiError 
-        {
-            error_code: "NETWORK_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })?;
-
-    if !response.ok()
-    {
-        return Err(parse_detailed_error_response(response).await);
-    }
-
-    Ok(())
 }
\ No newline at end of file
@@ -1,10 +1,15 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use futures::StreamExt;
-use gloo_net::eventsource::futures::EventSource;
+use gloo_net::eventsource::futures::{EventSource, EventSourceSubscription};
+use gloo_timers::callback::Timeout;
 use serde::Deserialize;
 use wasm_bindgen_futures::spawn_local;
 use yew::Callback;
 
 use crate::models::project::ProjectMetrics;
+use crate::services::api_client::ApiClient;
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -14,6 +19,8 @@ pub enum SseEvent
     ContainerStatus(ContainerStatusEvent),
     Metrics(MetricsEvent),
     System(SystemEvent),
+    Log(LogEvent),
+    BuildLog(BuildLogEvent),
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -64,6 +71,7 @@ pub enum DeploymentStage
         error: String,
         stage: String,
     },
+    Cancelled,
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -100,6 +108,32 @@ pub struct MetricsEvent
     pub timestamp: String,
 }
 
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct LogEvent
+{
+    pub project_id: i32,
+    pub stream: LogStream,
+    pub lines: Vec<String>,
+    pub timestamp: String,
+}
+
+/// Output of `git clone` and `docker build` while a GitHub project is being built.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct BuildLogEvent
+{
+    pub project_id: i32,
+    pub lines: Vec<String>,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStream
+{
+    Stdout,
+    Stderr,
+}
+
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct SystemEvent
 {
@@ -118,114 +152,365 @@ pub enum SystemEventLevel
     Error,
 }
 
-pub struct SseManager
+const EVENT_TYPES: [&str; 6] = [
+    "deployment",
+    "container_status",
+    "metrics",
+    "system",
+    "log",
+    "build_log",
+];
+
+const RECONNECT_BASE_DELAY_MS: u32 = 1000;
+const RECONNECT_MAX_DELAY_MS: u32 = 30000;
+const MAX_RECONNECT_ATTEMPTS: u32 = 10;
+/// How long a connection must stay open, without any message, before backoff starts over.
+/// A server that accepts the connection then drops it straight away must not reset it.
+const STABLE_CONNECTION_MS: u32 = 5000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SseConnectionStatus
 {
-    _phantom: std::marker::PhantomData<()>,
+    #[default]
+    Connecting,
+    Connected,
+    Reconnecting(u32),
+    Failed,
 }
 
-impl SseManager
+struct ConnectionState
 {
-    fn subscribe_to_event(
-        event_source: &mut EventSource,
-        event_type: &str,
-        on_message: Callback<SseEvent>,
-        on_error: Callback<String>,
-    )
+    url: String,
+    event_source: Option<EventSource>,
+    /// Bumped on every (re)connection so that streams of a replaced EventSource stop reacting.
+    generation: u32,
+    attempt: u32,
+    status: SseConnectionStatus,
+    last_event_id: Option<String>,
+    closed: bool,
+    on_message: Callback<SseEvent>,
+    on_status: Callback<SseConnectionStatus>,
+}
+
+impl ConnectionState
+{
+    /// The browser only sends `Last-Event-ID` on its own retries, so a fresh EventSource
+    /// carries it as a query parameter instead.
+    fn resume_url(&self) -> String
+    {
+        match &self.last_event_id
+        {
+            Some(id) =>
+            {
+                let separator = if self.url.contains('?') { '&' } else { '?' };
+                format!(
+                    "{}{}last_event_id={}",
+                    self.url,
+                    separator,
+                    js_sys::encode_uri_component(id)
+                )
+            }
+            None => self.url.clone(),
+        }
+    }
+}
+
+/// A server-sent events connection that reopens itself with exponential backoff
+/// whenever the browser gives up on the underlying EventSource.
+pub struct SseConnection
+{
+    state: Rc<RefCell<ConnectionState>>,
+}
+
+impl SseConnection
+{
+    /// Starts over with a fresh EventSource and a reset backoff, e.g. once the connection has `Failed`.
+    pub fn reconnect(&self)
+    {
+        let event_source =
+        {
+            let mut state = self.state.borrow_mut();
+            if state.closed
+            {
+                return;
+            }
+            state.attempt = 0;
+            state.event_source.take()
+        };
+
+        if let Some(es) = event_source
+        {
+            es.close();
+        }
+
+        Self::set_status(&self.state, SseConnectionStatus::Connecting);
+        Self::open(&self.state);
+    }
+
+    pub fn close(&self)
+    {
+        let event_source =
+        {
+            let mut state = self.state.borrow_mut();
+            state.closed = true;
+            state.event_source.take()
+        };
+
+        if let Some(es) = event_source
+        {
+            es.close();
+        }
+    }
+
+    fn open(state: &Rc<RefCell<ConnectionState>>)
     {
-        let event_type_owned = event_type.to_string();
+        let (url, generation) =
+        {
+            let mut s = state.borrow_mut();
+            s.generation += 1;
+            (s.resume_url(), s.generation)
+        };
+
+        let mut event_source = match EventSource::new(&url)
+        {
+            Ok(es) => es,
+            Err(e) =>
+            {
+                gloo_console::error!("Failed to create EventSource:", format!("{:?}", e));
+                Self::set_status(state, SseConnectionStatus::Failed);
+                return;
+            }
+        };
 
-        match event_source.subscribe(event_type)
+        // `open` carries a plain Event: only its arrival matters, never its payload.
+        if let Ok(mut subscription) = event_source.subscribe("open")
         {
-            Ok(mut subscription) =>
+            let state = state.clone();
+            spawn_local(async move
             {
-                spawn_local(async move 
+                while let Some(result) = subscription.next().await
                 {
-                    while let Some(message_result) = subscription.next().await
+                    if !Self::is_current(&state, generation)
+                    {
+                        break;
+                    }
+                    if result.is_ok()
                     {
-                        match message_result
+                        Self::set_status(&state, SseConnectionStatus::Connected);
+
+                        let state = state.clone();
+                        Timeout::new(STABLE_CONNECTION_MS, move ||
                         {
-                            Ok((_event_type, message)) =>
+                            if Self::is_current(&state, generation)
                             {
-                                if let Some(data_str) = message.data().as_string()
-                                {
-                                    match serde_json::from_str::<SseEvent>(&data_str)
-                                    {
-                                        Ok(event) => on_message.emit(event),
-                                        Err(e) =>
-                                        {
-                                            gloo_console::error!(
-                                                "Failed to parse SSE event",
-                                                &event_type_owned,
-                                                format!("{:?}", e)
-                                            );
-                                        }
-                                    }
-                                }
+                                state.borrow_mut().attempt = 0;
                             }
-                            Err(e) =>
+                        })
+                        .forget();
+                    }
+                }
+            });
+        }
+
+        for event_type in EVENT_TYPES
+        {
+            match event_source.subscribe(event_type)
+            {
+                Ok(subscription) => Self::listen(state.clone(), generation, event_type, subscription),
+                Err(e) =>
+                {
+                    gloo_console::error!("Failed to subscribe", event_type, format!("{:?}", e));
+                }
+            }
+        }
+
+        state.borrow_mut().event_source = Some(event_source);
+    }
+
+    fn listen(
+        state: Rc<RefCell<ConnectionState>>,
+        generation: u32,
+        event_type: &'static str,
+        mut subscription: EventSourceSubscription,
+    )
+    {
+        spawn_local(async move
+        {
+            while let Some(message_result) = subscription.next().await
+            {
+                if !Self::is_current(&state, generation)
+                {
+                    break;
+                }
+
+                match message_result
+                {
+                    Ok((_event_type, message)) =>
+                    {
+                        let last_event_id = message.last_event_id();
+                        if !last_event_id.is_empty()
+                        {
+                            state.borrow_mut().last_event_id = Some(last_event_id);
+                        }
+                        Self::mark_connected(&state);
+
+                        if let Some(data_str) = message.data().as_string()
+                        {
+                            match serde_json::from_str::<SseEvent>(&data_str)
                             {
-                                gloo_console::error!(
-                                    "Error in SSE stream",
-                                    &event_type_owned,
-                                    format!("{:?}", e)
-                                );
-                                on_error.emit(format!("SSE stream error ({})", event_type_owned));
-                                break;
+                                Ok(event) =>
+                                {
+                                    let on_message = state.borrow().on_message.clone();
+                                    on_message.emit(event);
+                                }
+                                Err(e) =>
+                                {
+                                    gloo_console::error!(
+                                        "Failed to parse SSE event",
+                                        event_type,
+                                        format!("{:?}", e)
+                                    );
+                                }
                             }
                         }
                     }
-                    gloo_console::log!("SSE subscription ended", &event_type_owned);
-                });
+                    Err(e) =>
+                    {
+                        gloo_console::error!(
+                            "Error in SSE stream",
+                            event_type,
+                            format!("{:?}", e)
+                        );
+                        Self::schedule_reconnect(&state, generation);
+                        break;
+                    }
+                }
             }
-            Err(e) =>
+        });
+    }
+
+    fn schedule_reconnect(state: &Rc<RefCell<ConnectionState>>, generation: u32)
+    {
+        let (attempt, event_source) =
+        {
+            let mut s = state.borrow_mut();
+            if s.closed || s.generation != generation
             {
-                gloo_console::error!("Failed to subscribe", event_type, format!("{:?}", e));
+                return;
             }
+            // Every subscription of the dead EventSource reports the same error: only the first one reconnects.
+            s.generation += 1;
+            s.attempt += 1;
+            (s.attempt, s.event_source.take())
+        };
+
+        if let Some(es) = event_source
+        {
+            es.close();
         }
+
+        if attempt > MAX_RECONNECT_ATTEMPTS
+        {
+            Self::set_status(state, SseConnectionStatus::Failed);
+            return;
+        }
+
+        Self::set_status(state, SseConnectionStatus::Reconnecting(attempt));
+
+        let expected_generation = generation + 1;
+        let state = state.clone();
+        Timeout::new(reconnect_delay_ms(attempt), move ||
+        {
+            let still_wanted =
+            {
+                let s = state.borrow();
+                !s.closed && s.generation == expected_generation
+            };
+
+            if still_wanted
+            {
+                Self::open(&state);
+            }
+        })
+        .forget();
+    }
+
+    fn is_current(state: &Rc<RefCell<ConnectionState>>, generation: u32) -> bool
+    {
+        let s = state.borrow();
+        !s.closed && s.generation == generation
+    }
+
+    /// A real message proves the connection works, unlike `open` alone.
+    fn mark_connected(state: &Rc<RefCell<ConnectionState>>)
+    {
+        state.borrow_mut().attempt = 0;
+        Self::set_status(state, SseConnectionStatus::Connected);
+    }
+
+    fn set_status(state: &Rc<RefCell<ConnectionState>>, status: SseConnectionStatus)
+    {
+        let on_status =
+        {
+            let mut s = state.borrow_mut();
+            if s.status == status
+            {
+                return;
+            }
+            s.status = status;
+            s.on_status.clone()
+        };
+        on_status.emit(status);
     }
 }
 
+/// Exponential backoff capped at `RECONNECT_MAX_DELAY_MS`, randomised to between half and all
+/// of the computed delay so that every open tab does not reconnect at the same instant.
+fn reconnect_delay_ms(attempt: u32) -> u32
+{
+    let exponential = RECONNECT_BASE_DELAY_MS.saturating_mul(1 << attempt.saturating_sub(1).min(16));
+    let capped = exponential.min(RECONNECT_MAX_DELAY_MS) as f64;
+    (capped * (0.5 + js_sys::Math::random() * 0.5)) as u32
+}
 
 pub fn connect_sse(
     url: &str,
     on_message: Callback<SseEvent>,
-    on_error: Callback<String>,
-) -> Result<EventSource, String>
-{
-    let event_source = gloo_net::eventsource::futures::EventSource::new(url)
-        .map_err(|e| format!("Failed to create EventSource: {:?}", e))?;
-    
-    let mut event_source = event_source;
-
-    let event_types = [
-        "deployment",
-        "container_status",
-        "metrics",
-        "system",
-    ];
-
-    for event_type in &event_types
+    on_status: Callback<SseConnectionStatus>,
+) -> SseConnection
+{
+    let state = Rc::new(RefCell::new(ConnectionState
     {
-        SseManager::subscribe_to_event(&mut event_source, event_type, on_message.clone(), on_error.clone());
-    }
+        url: url.to_string(),
+        event_source: None,
+        generation: 0,
+        attempt: 0,
+        status: SseConnectionStatus::Connecting,
+        last_event_id: None,
+        closed: false,
+        on_message,
+        on_status,
+    }));
+
+    SseConnection::open(&state);
 
-    Ok(event_source)
+    SseConnection { state }
 }
 
 pub fn connect_to_project(
     project_id: i32,
     on_message: Callback<SseEvent>,
-    on_error: Callback<String>,
-) -> Result<EventSource, String>
+    on_status: Callback<SseConnectionStatus>,
+) -> SseConnection
 {
-    let url = format!("/api/sse/projects/{}", project_id);
-    connect_sse(&url, on_message, on_error)
+    let url = ApiClient::default().url(&format!("/sse/projects/{}", project_id));
+    connect_sse(&url, on_message, on_status)
 }
 
 pub fn connect_to_creation(
     on_message: Callback<SseEvent>,
-    on_error: Callback<String>,
-) -> Result<EventSource, String>
+    on_status: Callback<SseConnectionStatus>,
+) -> SseConnection
 {
-    connect_sse("/api/sse/creation", on_message, on_error)
+    connect_sse(&ApiClient::default().url("/sse/creation"), on_message, on_status)
 }
\ No newline at end of file
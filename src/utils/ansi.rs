@@ -0,0 +1,360 @@
+use std::ops::Range;
+
+use yew::prelude::*;
+
+/// Standard 16-colour palette, tuned to stay readable on the dark logs background.
+const BASE_COLORS: [(u8, u8, u8); 16] = [
+    (0x4d, 0x4d, 0x4d), (0xe0, 0x6c, 0x75), (0x98, 0xc3, 0x79), (0xe5, 0xc0, 0x7b),
+    (0x61, 0xaf, 0xef), (0xc6, 0x78, 0xdd), (0x56, 0xb6, 0xc2), (0xd0, 0xd0, 0xd0),
+    (0x80, 0x80, 0x80), (0xff, 0x7b, 0x86), (0xb5, 0xe8, 0x90), (0xff, 0xd6, 0x8a),
+    (0x82, 0xc4, 0xff), (0xde, 0x9b, 0xf2), (0x7e, 0xd8, 0xe2), (0xff, 0xff, 0xff),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnsiColor
+{
+    /// Index into the xterm 256-colour palette
+    Palette(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl AnsiColor
+{
+    pub fn to_css(self) -> String
+    {
+        let (r, g, b) = match self
+        {
+            AnsiColor::Palette(index) => palette_rgb(index),
+            AnsiColor::Rgb(r, g, b) => (r, g, b),
+        };
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
+
+fn palette_rgb(index: u8) -> (u8, u8, u8)
+{
+    match index
+    {
+        0..=15 => BASE_COLORS[index as usize],
+        16..=231 =>
+        {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = index - 16;
+            (level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        _ =>
+        {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AnsiStyle
+{
+    pub foreground: Option<AnsiColor>,
+    pub background: Option<AnsiColor>,
+    pub bold: bool,
+    pub underline: bool,
+}
+
+impl AnsiStyle
+{
+    pub fn is_plain(self) -> bool
+    {
+        self == AnsiStyle::default()
+    }
+
+    /// Inline CSS for the span, empty for unstyled text.
+    pub fn to_css(self) -> String
+    {
+        let mut css = String::new();
+        if let Some(color) = self.foreground
+        {
+            css.push_str(&format!("color: {};", color.to_css()));
+        }
+        if let Some(color) = self.background
+        {
+            css.push_str(&format!("background-color: {};", color.to_css()));
+        }
+        if self.bold
+        {
+            css.push_str("font-weight: 700;");
+        }
+        if self.underline
+        {
+            css.push_str("text-decoration: underline;");
+        }
+        css
+    }
+
+    fn apply_sgr(&mut self, params: &str)
+    {
+        // `ESC[m` is the same as `ESC[0m`
+        let mut codes: Vec<u16> = Vec::new();
+        for group in params.split(';')
+        {
+            let mut sub: Vec<u16> = group.split(':').map(|p| p.parse().unwrap_or(0)).collect();
+            // The colon form of truecolour may carry a colour space id: `38:2:<id>:r:g:b`
+            if matches!(sub[..], [38 | 48, 2, _, _, _, _])
+            {
+                sub.remove(2);
+            }
+            codes.extend(sub);
+        }
+
+        let mut i = 0;
+        while i < codes.len()
+        {
+            match codes[i]
+            {
+                0 => *self = AnsiStyle::default(),
+                1 => self.bold = true,
+                22 => self.bold = false,
+                4 => self.underline = true,
+                24 => self.underline = false,
+                code @ 30..=37 => self.foreground = Some(AnsiColor::Palette((code - 30) as u8)),
+                code @ 90..=97 => self.foreground = Some(AnsiColor::Palette((code - 90 + 8) as u8)),
+                39 => self.foreground = None,
+                code @ 40..=47 => self.background = Some(AnsiColor::Palette((code - 40) as u8)),
+                code @ 100..=107 => self.background = Some(AnsiColor::Palette((code - 100 + 8) as u8)),
+                49 => self.background = None,
+                code @ (38 | 48) =>
+                {
+                    let (color, consumed) = extended_color(&codes[i + 1..]);
+                    if code == 38
+                    {
+                        self.foreground = color.or(self.foreground);
+                    }
+                    else
+                    {
+                        self.background = color.or(self.background);
+                    }
+                    i += consumed;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+}
+
+/// Reads the arguments of a `38;…`/`48;…` code, returning the colour and how many codes it used.
+fn extended_color(args: &[u16]) -> (Option<AnsiColor>, usize)
+{
+    match args
+    {
+        [5, index, ..] => (Some(AnsiColor::Palette(*index as u8)), 2),
+        [2, r, g, b, ..] => (Some(AnsiColor::Rgb(*r as u8, *g as u8, *b as u8)), 4),
+        _ => (None, args.len()),
+    }
+}
+
+/// A log message with its escape codes removed and the styles they described.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnsiText
+{
+    pub text: String,
+    /// Byte ranges of `text`, contiguous and in order.
+    pub spans: Vec<(Range<usize>, AnsiStyle)>,
+}
+
+impl AnsiText
+{
+    fn push(&mut self, c: char, style: AnsiStyle)
+    {
+        let start = self.text.len();
+        self.text.push(c);
+        let end = self.text.len();
+
+        match self.spans.last_mut()
+        {
+            Some((range, last_style)) if *last_style == style => range.end = end,
+            _ => self.spans.push((start..end, style)),
+        }
+    }
+}
+
+/// Parses SGR sequences (colours, bold, underline) into styled spans.
+/// Every other escape sequence, such as cursor movement or line erasing, is dropped.
+pub fn parse_ansi(input: &str) -> AnsiText
+{
+    let mut parsed = AnsiText::default();
+    let mut style = AnsiStyle::default();
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next()
+    {
+        if c != '\x1b'
+        {
+            parsed.push(c, style);
+            continue;
+        }
+
+        match chars.next()
+        {
+            // CSI: parameters then a final byte in `@`..=`~`
+            Some('[') =>
+            {
+                let mut params = String::new();
+                for c in chars.by_ref()
+                {
+                    if ('@'..='~').contains(&c)
+                    {
+                        if c == 'm'
+                        {
+                            style.apply_sgr(&params);
+                        }
+                        break;
+                    }
+                    params.push(c);
+                }
+            }
+            // OSC and the other control strings: terminated by BEL or `ESC \`
+            Some(']' | 'P' | 'X' | '^' | '_') =>
+            {
+                while let Some(c) = chars.next()
+                {
+                    if c == '\x07'
+                    {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\')
+                    {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            // Two-character escapes such as `ESC=`, optionally with intermediate
+            // bytes before the final one, such as the charset selection `ESC(B`
+            Some(' '..='/') =>
+            {
+                while chars.next_if(|c| (' '..='/').contains(c)).is_some() {}
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+
+    parsed
+}
+
+/// Renders the styled spans of `message`, wrapping the parts covered by `highlights` in `<mark>`.
+pub fn render_message(message: &AnsiText, highlights: &[Range<usize>]) -> Html
+{
+    let mut segments = Vec::new();
+
+    for (range, style) in &message.spans
+    {
+        let mut pos = range.start;
+        while pos < range.end
+        {
+            let current = highlights.iter().find(|h| h.contains(&pos));
+            let end = match current
+            {
+                Some(h) => h.end,
+                None => highlights
+                    .iter()
+                    .map(|h| h.start)
+                    .filter(|&start| start > pos)
+                    .min()
+                    .unwrap_or(range.end),
+            }
+            .min(range.end);
+
+            let text = &message.text[pos..end];
+            let segment = if style.is_plain()
+            {
+                html! { text }
+            }
+            else
+            {
+                html! { <span style={style.to_css()}>{ text }</span> }
+            };
+
+            segments.push(if current.is_some()
+            {
+                html! { <mark class="log-highlight">{ segment }</mark> }
+            }
+            else
+            {
+                segment
+            });
+            pos = end;
+        }
+    }
+
+    segments.into_iter().collect()
+}
+
+pub fn strip_ansi(input: &str) -> String
+{
+    parse_ansi(input).text
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn red() -> AnsiStyle
+    {
+        AnsiStyle { foreground: Some(AnsiColor::Palette(1)), ..Default::default() }
+    }
+
+    #[test]
+    fn sgr_reset()
+    {
+        let parsed = parse_ansi("\x1b[1;31mfail\x1b[0m ok\x1b[31m!\x1b[m.");
+        assert_eq!(parsed.text, "fail ok!.");
+        assert_eq!(parsed.spans, vec![
+            (0..4, AnsiStyle { bold: true, ..red() }),
+            (4..7, AnsiStyle::default()),
+            (7..8, red()),
+            (8..9, AnsiStyle::default()),
+        ]);
+    }
+
+    #[test]
+    fn palette_256_colours()
+    {
+        let parsed = parse_ansi("\x1b[38;5;196;48:5:21mx");
+        assert_eq!(parsed.spans, vec![(0..1, AnsiStyle
+        {
+            foreground: Some(AnsiColor::Palette(196)),
+            background: Some(AnsiColor::Palette(21)),
+            ..Default::default()
+        })]);
+        assert_eq!(AnsiColor::Palette(196).to_css(), "#ff0000");
+        assert_eq!(AnsiColor::Palette(232).to_css(), "#080808");
+    }
+
+    #[test]
+    fn truecolour()
+    {
+        let rgb = AnsiStyle { foreground: Some(AnsiColor::Rgb(255, 128, 0)), ..Default::default() };
+        for input in ["\x1b[38;2;255;128;0mx", "\x1b[38:2:255:128:0mx", "\x1b[38:2::255:128:0mx"]
+        {
+            assert_eq!(parse_ansi(input).spans, vec![(0..1, rgb)], "{:?}", input);
+        }
+
+        // Colon sub-parameters stay within their group
+        let parsed = parse_ansi("\x1b[38:2:255:128:0;1mx");
+        assert_eq!(parsed.spans, vec![(0..1, AnsiStyle { bold: true, ..rgb })]);
+    }
+
+    #[test]
+    fn osc_dropped()
+    {
+        assert_eq!(strip_ansi("\x1b]0;title\x07a\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"), "alink");
+    }
+
+    #[test]
+    fn charset_and_two_character_escapes_dropped()
+    {
+        assert_eq!(strip_ansi("\x1b(Bplain\x1b)0 \x1b=keypad\x1b>\x1b7x\x1b8"), "plain keypadx");
+        assert_eq!(strip_ansi("\x1b[2K\x1b[1Gprogress"), "progress");
+    }
+}
\ No newline at end of file
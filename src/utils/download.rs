@@ -0,0 +1,39 @@
+use gloo_timers::callback::Timeout;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+const REVOKE_DELAY_MS: u32 = 1000;
+
+/// Makes the browser save `content` as `filename`, without any round trip to the backend.
+pub fn download_text(filename: &str, content: &str, mime_type: &str) -> Result<(), JsValue>
+{
+    let parts = js_sys::Array::of1(&JsValue::from_str(content));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| JsValue::from_str("No document available"))?;
+    let anchor = document
+        .create_element("a")?
+        .dyn_into::<HtmlAnchorElement>()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+
+    // Firefox ignores clicks on anchors that are not in the document
+    let body = document.body().ok_or_else(|| JsValue::from_str("No body available"))?;
+    body.append_child(&anchor)?;
+    anchor.click();
+    anchor.remove();
+
+    // Revoking right away can cancel the download before the browser has read the blob
+    Timeout::new(REVOKE_DELAY_MS, move ||
+    {
+        let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,103 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::utils::download::download_text;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat
+{
+    Csv,
+    Json,
+}
+
+impl ExportFormat
+{
+    pub fn extension(self) -> &'static str
+    {
+        match self
+        {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str
+    {
+        match self
+        {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Json => "application/json",
+        }
+    }
+}
+
+/// Serializes `rows` as a JSON array, or as CSV with one column per entry of `columns`, in that order.
+/// `columns` must name fields of `T`.
+pub fn export_rows<T: Serialize>(rows: &[T], columns: &[&str], format: ExportFormat) -> Result<String, serde_json::Error>
+{
+    match format
+    {
+        ExportFormat::Json => serde_json::to_string_pretty(rows),
+        ExportFormat::Csv =>
+        {
+            let mut csv = columns.join(",");
+
+            for row in rows
+            {
+                let value = serde_json::to_value(row)?;
+                let cells = columns
+                    .iter()
+                    .map(|column| match value.get(column)
+                    {
+                        Some(Value::String(s)) => csv_escape(s),
+                        Some(Value::Null) | None => String::new(),
+                        Some(other) => other.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+
+                csv.push('\n');
+                csv.push_str(&cells.join(","));
+            }
+
+            Ok(csv)
+        }
+    }
+}
+
+/// Exports `rows` and has the browser save them as `{filename_stem}.csv` or `.json`.
+pub fn download_rows<T: Serialize>(filename_stem: &str, rows: &[T], columns: &[&str], format: ExportFormat)
+{
+    let content = match export_rows(rows, columns, format)
+    {
+        Ok(content) => content,
+        Err(e) =>
+        {
+            gloo_console::error!("Failed to export rows:", e.to_string());
+            return;
+        }
+    };
+
+    let filename = format!("{}.{}", filename_stem, format.extension());
+    if let Err(e) = download_text(&filename, &content, format.mime_type())
+    {
+        gloo_console::error!("Failed to download export:", e);
+    }
+}
+
+fn csv_escape(cell: &str) -> String
+{
+    if cell.contains([',', '"', '\n', '\r'])
+    {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    }
+    else
+    {
+        cell.to_string()
+    }
+}
+
+/// `YYYY-MM-DDTHH:MM:SS.sssZ` for a number of milliseconds since the epoch.
+pub fn iso_timestamp(ms: f64) -> String
+{
+    js_sys::Date::new(&ms.into()).to_iso_string().into()
+}
\ No newline at end of file
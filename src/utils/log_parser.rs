@@ -0,0 +1,379 @@
+use std::ops::Range;
+
+use regex::{Regex, RegexBuilder};
+use serde_json::Value;
+
+use crate::utils::ansi::{parse_ansi, AnsiText};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevel
+{
+    Error,
+    Warn,
+    Info,
+}
+
+impl LogLevel
+{
+    pub fn css_class(self) -> &'static str
+    {
+        match self
+        {
+            LogLevel::Error => "log-error",
+            LogLevel::Warn => "log-warn",
+            LogLevel::Info => "log-info",
+        }
+    }
+
+    pub fn label(self) -> &'static str
+    {
+        match self
+        {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+        }
+    }
+}
+
+/// Splits a `docker logs --timestamps` line into its RFC3339 timestamp and message.
+/// Lines without a leading timestamp get an empty one.
+pub fn parse_log_line(line: &str) -> (&str, &str)
+{
+    let parts: Vec<&str> = line.splitn(2, ' ').collect();
+    if parts.len() == 2 && parts[0].ends_with('Z')
+    {
+        (parts[0], parts[1].trim())
+    }
+    else
+    {
+        ("", line)
+    }
+}
+
+pub fn format_timestamp(timestamp: &str) -> &str
+{
+    timestamp.split('.').next().unwrap_or(timestamp)
+}
+
+/// Milliseconds since the epoch, `None` if `timestamp` is empty or unparsable.
+/// Accepts RFC3339 as well as the local `YYYY-MM-DDTHH:MM` of `datetime-local` inputs.
+pub fn timestamp_ms(timestamp: &str) -> Option<f64>
+{
+    if timestamp.is_empty()
+    {
+        return None;
+    }
+
+    let ms = js_sys::Date::new(&timestamp.into()).get_time();
+    (!ms.is_nan()).then_some(ms)
+}
+
+pub fn determine_log_level(message: &str) -> LogLevel
+{
+    let message_upper = message.to_uppercase();
+    if message_upper.contains("ERROR") || message_upper.contains("FAILED")
+    {
+        LogLevel::Error
+    }
+    else if message_upper.contains("WARN") || message_upper.contains("WARNING")
+    {
+        LogLevel::Warn
+    }
+    else
+    {
+        LogLevel::Info
+    }
+}
+
+/// Keys holding the human-readable message, by order of preference.
+const MESSAGE_KEYS: [&str; 4] = ["message", "msg", "event", "fields.message"];
+
+/// A JSON log line, as written by tracing-subscriber, pino or structlog.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StructuredLog
+{
+    /// `None` when the object has no recognisable `level` field.
+    pub level: Option<LogLevel>,
+    pub message: String,
+    /// Remaining fields, nested objects flattened into dotted keys.
+    pub fields: Vec<(String, String)>,
+}
+
+/// Parses `message` as a structured log, `None` if it is not a JSON object.
+pub fn parse_structured_log(message: &str) -> Option<StructuredLog>
+{
+    if !message.starts_with('{')
+    {
+        return None;
+    }
+
+    let Ok(Value::Object(object)) = serde_json::from_str::<Value>(message)
+    else
+    {
+        return None;
+    };
+
+    let mut fields = Vec::new();
+    for (key, value) in &object
+    {
+        flatten_field(key.clone(), value, &mut fields);
+    }
+
+    let level = object.get("level").and_then(level_from_field);
+    if level.is_some()
+    {
+        fields.retain(|(key, _)| key != "level");
+    }
+
+    let message = MESSAGE_KEYS
+        .iter()
+        .find_map(|key| fields.iter().position(|(k, _)| k == key))
+        .map(|pos| fields.remove(pos).1)
+        .unwrap_or_default();
+
+    Some(StructuredLog { level, message, fields })
+}
+
+fn flatten_field(key: String, value: &Value, fields: &mut Vec<(String, String)>)
+{
+    match value
+    {
+        Value::Object(object) if !object.is_empty() =>
+        {
+            for (child_key, child) in object
+            {
+                flatten_field(format!("{}.{}", key, child_key), child, fields);
+            }
+        }
+        Value::String(s) => fields.push((key, s.clone())),
+        other => fields.push((key, other.to_string())),
+    }
+}
+
+/// Understands textual levels as well as pino's numeric ones (40 = warn, 50 = error).
+fn level_from_field(value: &Value) -> Option<LogLevel>
+{
+    match value
+    {
+        Value::String(level) => match level.to_lowercase().as_str()
+        {
+            "error" | "err" | "fatal" | "critical" | "panic" => Some(LogLevel::Error),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "info" | "debug" | "trace" | "notice" => Some(LogLevel::Info),
+            _ => None,
+        },
+        Value::Number(level) => level.as_u64().map(|level| match level
+        {
+            50.. => LogLevel::Error,
+            40..50 => LogLevel::Warn,
+            _ => LogLevel::Info,
+        }),
+        _ => None,
+    }
+}
+
+/// A log line parsed once when it is received, so that filtering and rendering
+/// never have to go through the escape codes or the JSON again.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedLogLine
+{
+    pub raw: String,
+    pub timestamp: String,
+    /// `timestamp` in milliseconds since the epoch, for the time range filter
+    pub timestamp_ms: Option<f64>,
+    /// Message as searched: the whole line after the timestamp, escape codes removed.
+    pub text: String,
+    /// Message as displayed: the `message` field for structured logs, the line itself otherwise.
+    pub message: AnsiText,
+    pub structured: Option<StructuredLog>,
+    pub level: LogLevel,
+}
+
+impl ParsedLogLine
+{
+    pub fn parse(raw: &str) -> Self
+    {
+        let (timestamp, message) = parse_log_line(raw);
+        let message = parse_ansi(message);
+        let structured = parse_structured_log(&message.text);
+        let level = structured
+            .as_ref()
+            .and_then(|s| s.level)
+            .unwrap_or_else(|| determine_log_level(&message.text));
+
+        let (text, message) = match &structured
+        {
+            Some(s) => (message.text, parse_ansi(&s.message)),
+            None => (message.text.clone(), message),
+        };
+
+        ParsedLogLine
+        {
+            raw: raw.to_string(),
+            timestamp: timestamp.to_string(),
+            timestamp_ms: timestamp_ms(timestamp),
+            text,
+            message,
+            structured,
+            level,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogFilter
+{
+    pub query: String,
+    pub use_regex: bool,
+    /// Space-separated `key=value` terms, matched against structured log fields.
+    pub fields: String,
+    pub show_error: bool,
+    pub show_warn: bool,
+    pub show_info: bool,
+    pub from_ms: Option<f64>,
+    pub to_ms: Option<f64>,
+}
+
+impl Default for LogFilter
+{
+    fn default() -> Self
+    {
+        LogFilter
+        {
+            query: String::new(),
+            use_regex: false,
+            fields: String::new(),
+            show_error: true,
+            show_warn: true,
+            show_info: true,
+            from_ms: None,
+            to_ms: None,
+        }
+    }
+}
+
+impl LogFilter
+{
+    /// Builds the search matcher, `None` when there is nothing to search for.
+    /// Plain-text searches are case-insensitive, regexes are used as written.
+    pub fn matcher(&self) -> Result<Option<Regex>, regex::Error>
+    {
+        if self.query.is_empty()
+        {
+            return Ok(None);
+        }
+
+        let pattern = if self.use_regex
+        {
+            self.query.clone()
+        }
+        else
+        {
+            regex::escape(&self.query)
+        };
+
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.use_regex)
+            .build()
+            .map(Some)
+    }
+
+    /// Parsed `key=value` terms of the field filter; a bare `key` only requires the field to exist.
+    pub fn field_terms(&self) -> Vec<(&str, Option<&str>)>
+    {
+        self.fields
+            .split_whitespace()
+            .map(|term| match term.split_once('=')
+            {
+                Some((key, value)) => (key, Some(value)),
+                None => (term, None),
+            })
+            .collect()
+    }
+
+    /// Prepares the filter to be applied to many lines, with `matcher` built by `matcher()`.
+    pub fn compile<'a>(&'a self, matcher: Option<&'a Regex>) -> CompiledLogFilter<'a>
+    {
+        CompiledLogFilter
+        {
+            filter: self,
+            matcher,
+            field_terms: self.field_terms(),
+        }
+    }
+}
+
+/// A `LogFilter` whose field terms are parsed once rather than for every line.
+pub struct CompiledLogFilter<'a>
+{
+    filter: &'a LogFilter,
+    matcher: Option<&'a Regex>,
+    field_terms: Vec<(&'a str, Option<&'a str>)>,
+}
+
+impl CompiledLogFilter<'_>
+{
+    pub fn accepts(&self, line: &ParsedLogLine) -> bool
+    {
+        let filter = self.filter;
+        let level_shown = match line.level
+        {
+            LogLevel::Error => filter.show_error,
+            LogLevel::Warn => filter.show_warn,
+            LogLevel::Info => filter.show_info,
+        };
+
+        if !level_shown
+        {
+            return false;
+        }
+
+        if filter.from_ms.is_some() || filter.to_ms.is_some()
+        {
+            // Lines without a timestamp cannot be placed in a time range
+            let Some(ms) = line.timestamp_ms
+            else
+            {
+                return false;
+            };
+
+            if filter.from_ms.is_some_and(|from| ms < from) || filter.to_ms.is_some_and(|to| ms > to)
+            {
+                return false;
+            }
+        }
+
+        if !self.field_terms.is_empty()
+        {
+            // Plain-text lines have no fields to match against
+            let Some(fields) = line.structured.as_ref().map(|s| &s.fields)
+            else
+            {
+                return false;
+            };
+
+            let matches_term = |(key, value): &(&str, Option<&str>)|
+            {
+                fields
+                    .iter()
+                    .any(|(k, v)| k == key && value.is_none_or(|value| v == value))
+            };
+
+            if !self.field_terms.iter().all(matches_term)
+            {
+                return false;
+            }
+        }
+
+        self.matcher.is_none_or(|m| m.is_match(&line.text))
+    }
+}
+
+/// Byte ranges of the non-empty matches of `matcher` in `text`, for highlighting.
+pub fn match_ranges(text: &str, matcher: Option<&Regex>) -> Vec<Range<usize>>
+{
+    matcher
+        .map(|m| m.find_iter(text).map(|found| found.range()).filter(|r| !r.is_empty()).collect())
+        .unwrap_or_default()
+}
\ No newline at end of file
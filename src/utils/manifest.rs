@@ -0,0 +1,170 @@
+use crate::models::manifest::{ProjectManifest, MANIFEST_VERSION};
+use crate::utils::download::download_text;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ManifestFormat
+{
+    Json,
+    Toml,
+}
+
+impl ManifestFormat
+{
+    pub fn filename(self) -> &'static str
+    {
+        match self
+        {
+            ManifestFormat::Json => "hangar.json",
+            ManifestFormat::Toml => "hangar.toml",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str
+    {
+        match self
+        {
+            ManifestFormat::Json => "application/json",
+            ManifestFormat::Toml => "application/toml",
+        }
+    }
+
+    /// Picks the format from the uploaded file's extension.
+    pub fn from_filename(filename: &str) -> Option<Self>
+    {
+        let extension = filename.rsplit_once('.')?.1.to_ascii_lowercase();
+        match extension.as_str()
+        {
+            "json" => Some(ManifestFormat::Json),
+            "toml" => Some(ManifestFormat::Toml),
+            _ => None,
+        }
+    }
+}
+
+pub fn serialize_manifest(manifest: &ProjectManifest, format: ManifestFormat) -> Result<String, String>
+{
+    match format
+    {
+        ManifestFormat::Json => serde_json::to_string_pretty(manifest).map_err(|e| e.to_string()),
+        ManifestFormat::Toml => toml::to_string_pretty(manifest).map_err(|e| e.to_string()),
+    }
+}
+
+/// Parses an uploaded manifest; the error is a parser message meant to be shown as details.
+pub fn parse_manifest(content: &str, format: ManifestFormat) -> Result<ProjectManifest, String>
+{
+    let manifest: ProjectManifest = match format
+    {
+        ManifestFormat::Json => serde_json::from_str(content).map_err(|e| e.to_string())?,
+        ManifestFormat::Toml => toml::from_str(content).map_err(|e| e.to_string())?,
+    };
+
+    if manifest.version > MANIFEST_VERSION
+    {
+        return Err(format!("Unsupported manifest version {} (expected at most {})", manifest.version, MANIFEST_VERSION));
+    }
+
+    Ok(manifest)
+}
+
+/// Has the browser save the manifest as `hangar.json` or `hangar.toml`.
+pub fn download_manifest(manifest: &ProjectManifest, format: ManifestFormat)
+{
+    let content = match serialize_manifest(manifest, format)
+    {
+        Ok(content) => content,
+        Err(e) =>
+        {
+            gloo_console::error!("Failed to export manifest:", e);
+            return;
+        }
+    };
+
+    if let Err(e) = download_text(format.filename(), &content, format.mime_type())
+    {
+        gloo_console::error!("Failed to download manifest:", e);
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::models::manifest::ManifestSource;
+
+    fn github_manifest() -> ProjectManifest
+    {
+        ProjectManifest
+        {
+            version: MANIFEST_VERSION,
+            name: Some("my-app".to_string()),
+            source: ManifestSource::Github
+            {
+                repository: "https://github.com/org/my-app".to_string(),
+                branch: Some("main".to_string()),
+                root_dir: None,
+            },
+            participants: vec!["alice".to_string(), "bob".to_string()],
+            env: vec!["DATABASE_URL".to_string(), "SECRET_KEY".to_string()],
+            volume_path: Some("/app/data".to_string()),
+            database: true,
+        }
+    }
+
+    fn direct_manifest() -> ProjectManifest
+    {
+        ProjectManifest
+        {
+            version: MANIFEST_VERSION,
+            name: None,
+            source: ManifestSource::Direct
+            {
+                image: "ghcr.io/org/app:1.0".to_string(),
+            },
+            participants: vec![],
+            env: vec![],
+            volume_path: None,
+            database: false,
+        }
+    }
+
+    #[test]
+    fn round_trip()
+    {
+        for format in [ManifestFormat::Json, ManifestFormat::Toml]
+        {
+            for manifest in [github_manifest(), direct_manifest()]
+            {
+                let content = serialize_manifest(&manifest, format).unwrap();
+                assert_eq!(parse_manifest(&content, format), Ok(manifest), "{:?}: {}", format, content);
+            }
+        }
+    }
+
+    #[test]
+    fn newer_version_refused()
+    {
+        let json = format!(r#"{{ "version": {}, "source": {{ "type": "direct", "image": "nginx" }} }}"#, MANIFEST_VERSION + 1);
+        assert!(parse_manifest(&json, ManifestFormat::Json).is_err());
+
+        let toml = format!("version = {}\n\n[source]\ntype = \"direct\"\nimage = \"nginx\"\n", MANIFEST_VERSION + 1);
+        assert!(parse_manifest(&toml, ManifestFormat::Toml).is_err());
+    }
+
+    #[test]
+    fn minimal_toml()
+    {
+        let toml = "version = 1\n\n[source]\ntype = \"github\"\nrepository = \"https://github.com/org/app\"\n";
+        let manifest = parse_manifest(toml, ManifestFormat::Toml).unwrap();
+        assert_eq!(manifest.env, Vec::<String>::new());
+        assert!(!manifest.database);
+    }
+
+    #[test]
+    fn imported_env_values_are_empty()
+    {
+        let env_vars = github_manifest().into_payload().env_vars.unwrap();
+        assert_eq!(env_vars.len(), 2);
+        assert!(env_vars.values().all(String::is_empty));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+pub mod ansi;
+pub mod download;
+pub mod export;
+pub mod log_parser;
+pub mod manifest;
+pub mod scan_report;
+pub mod units;
+pub mod validation;
\ No newline at end of file
@@ -0,0 +1,195 @@
+use serde::Deserialize;
+
+use crate::models::scan::{Severity, Vulnerability};
+
+#[derive(Deserialize)]
+struct GrypeReport
+{
+    matches: Vec<GrypeMatch>,
+}
+
+#[derive(Deserialize)]
+struct GrypeMatch
+{
+    vulnerability: GrypeVulnerability,
+    artifact: GrypeArtifact,
+}
+
+#[derive(Deserialize)]
+struct GrypeVulnerability
+{
+    id: String,
+    severity: Severity,
+    #[serde(default)]
+    fix: Option<GrypeFix>,
+}
+
+#[derive(Deserialize)]
+struct GrypeFix
+{
+    #[serde(default)]
+    versions: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct GrypeArtifact
+{
+    name: String,
+    version: String,
+}
+
+/// Vulnerabilities listed in the details of an `IMAGE_SCAN_FAILED` error.
+/// Accepts Grype's JSON output as well as its default table output, `None` if neither matches.
+pub fn parse_scan_details(details: &str) -> Option<Vec<Vulnerability>>
+{
+    parse_grype_json(details).or_else(|| parse_grype_table(details))
+}
+
+fn parse_grype_json(details: &str) -> Option<Vec<Vulnerability>>
+{
+    let report = serde_json::from_str::<GrypeReport>(details.trim()).ok()?;
+
+    Some(report.matches.into_iter().map(|m| Vulnerability
+    {
+        id: m.vulnerability.id,
+        severity: m.vulnerability.severity,
+        package: m.artifact.name,
+        installed_version: m.artifact.version,
+        fixed_version: m.vulnerability.fix.and_then(|f| (!f.versions.is_empty()).then(|| f.versions.join(", "))),
+    }).collect())
+}
+
+/// Columns are aligned on the header, and FIXED-IN is left blank when there is no fix,
+/// so rows are sliced at the header offsets rather than split on whitespace.
+fn parse_grype_table(details: &str) -> Option<Vec<Vulnerability>>
+{
+    let mut lines = details.lines().skip_while(|l| !(l.contains("VULNERABILITY") && l.contains("SEVERITY")));
+    let header = lines.next()?;
+
+    let column = |name: &str| header.find(name);
+    let name_at = column("NAME")?;
+    let installed_at = column("INSTALLED")?;
+    let fixed_at = column("FIXED-IN")?;
+    let id_at = column("VULNERABILITY")?;
+    let severity_at = column("SEVERITY")?;
+
+    // Every column starts where a header word does
+    let offsets = header
+        .match_indices(|c: char| !c.is_whitespace())
+        .map(|(i, _)| i)
+        .filter(|&i| i == 0 || header[..i].ends_with(' '))
+        .collect::<Vec<_>>();
+
+    let cell = |row: &str, start: usize| -> String
+    {
+        let end = offsets.iter().copied().find(|&o| o > start).unwrap_or(row.len());
+        row.get(start.min(row.len())..end.min(row.len())).unwrap_or("").trim().to_string()
+    };
+
+    let vulnerabilities = lines
+        .filter(|row| !row.trim().is_empty())
+        .map(|row|
+        {
+            let fixed = cell(row, fixed_at);
+            Vulnerability
+            {
+                id: cell(row, id_at),
+                severity: Severity::from(cell(row, severity_at)),
+                package: cell(row, name_at),
+                installed_version: cell(row, installed_at),
+                fixed_version: (!fixed.is_empty() && fixed != "(won't fix)").then_some(fixed),
+            }
+        })
+        .filter(|v| !v.id.is_empty())
+        .collect();
+
+    Some(vulnerabilities)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn vulnerability(id: &str, severity: Severity, package: &str, installed: &str, fixed: Option<&str>) -> Vulnerability
+    {
+        Vulnerability
+        {
+            id: id.to_string(),
+            severity,
+            package: package.to_string(),
+            installed_version: installed.to_string(),
+            fixed_version: fixed.map(str::to_string),
+        }
+    }
+
+    const GRYPE_TABLE: &str = "\
+NAME        INSTALLED       FIXED-IN     TYPE  VULNERABILITY   SEVERITY
+libssl3     3.0.11-1~deb12  3.0.13-1     deb   CVE-2024-0727   Medium
+libc6       2.36-9+deb12u3               deb   CVE-2010-4756   Negligible
+zlib1g      1:1.2.13.dfsg-1 (won't fix)  deb   CVE-2023-45853  Critical
+";
+
+    #[test]
+    fn table_blank_and_wont_fix_columns()
+    {
+        assert_eq!(parse_scan_details(GRYPE_TABLE), Some(vec![
+            vulnerability("CVE-2024-0727", Severity::Medium, "libssl3", "3.0.11-1~deb12", Some("3.0.13-1")),
+            vulnerability("CVE-2010-4756", Severity::Negligible, "libc6", "2.36-9+deb12u3", None),
+            vulnerability("CVE-2023-45853", Severity::Critical, "zlib1g", "1:1.2.13.dfsg-1", None),
+        ]));
+    }
+
+    #[test]
+    fn table_after_progress_output()
+    {
+        let details = format!(
+            " ✔ Vulnerability DB                [no update available]\n \
+             ✔ Loaded image                     nginx:latest\n \
+             ✔ Cataloged contents               [142 packages]\n \
+             ✔ Scanned for vulnerabilities      [3 vulnerability matches]\n{}",
+            GRYPE_TABLE
+        );
+
+        let vulnerabilities = parse_scan_details(&details).unwrap();
+        assert_eq!(vulnerabilities.len(), 3);
+        assert_eq!(vulnerabilities[0].id, "CVE-2024-0727");
+    }
+
+    #[test]
+    fn json_matches()
+    {
+        let details = r#"{
+            "matches": [
+                {
+                    "vulnerability": {
+                        "id": "GHSA-jfh8-c2jp-5v3q",
+                        "severity": "High",
+                        "fix": { "versions": ["2.17.1"], "state": "fixed" }
+                    },
+                    "artifact": { "name": "log4j-core", "version": "2.14.1", "type": "java-archive" }
+                },
+                {
+                    "vulnerability": {
+                        "id": "CVE-2023-45853",
+                        "severity": "Critical",
+                        "fix": { "versions": [], "state": "wont-fix" }
+                    },
+                    "artifact": { "name": "zlib1g", "version": "1:1.2.13.dfsg-1", "type": "deb" }
+                }
+            ],
+            "source": { "type": "image" }
+        }"#;
+
+        assert_eq!(parse_scan_details(details), Some(vec![
+            vulnerability("GHSA-jfh8-c2jp-5v3q", Severity::High, "log4j-core", "2.14.1", Some("2.17.1")),
+            vulnerability("CVE-2023-45853", Severity::Critical, "zlib1g", "1:1.2.13.dfsg-1", None),
+        ]));
+    }
+
+    #[test]
+    fn unrecognised_details()
+    {
+        assert_eq!(parse_scan_details("Scan timed out"), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+const BYTE_UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+/// Human-readable size using binary prefixes, e.g. `1.5 MiB`.
+pub fn format_bytes(bytes: f64) -> String
+{
+    let mut value = bytes.max(0.0);
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < BYTE_UNITS.len() - 1
+    {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0
+    {
+        format!("{:.0} {}", value, BYTE_UNITS[unit])
+    }
+    else
+    {
+        format!("{:.1} {}", value, BYTE_UNITS[unit])
+    }
+}
+
+pub fn format_bytes_rate(bytes_per_sec: f64) -> String
+{
+    format!("{}/s", format_bytes(bytes_per_sec))
+}
\ No newline at end of file
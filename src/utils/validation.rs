@@ -0,0 +1,360 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// Longest label allowed by DNS; the project name becomes `<name>.hangar.garageisep.com`.
+const MAX_PROJECT_NAME_LEN: usize = 63;
+/// Docker rejects repository names (registry included) longer than this.
+const MAX_IMAGE_NAME_LEN: usize = 255;
+
+static REGISTRY_RE: LazyLock<Regex> = LazyLock::new(||
+{
+    Regex::new(r"^(?:[a-zA-Z0-9](?:[a-zA-Z0-9-]*[a-zA-Z0-9])?)(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]*[a-zA-Z0-9])?)*(?::[0-9]+)?$").unwrap()
+});
+static PATH_COMPONENT_RE: LazyLock<Regex> = LazyLock::new(||
+{
+    Regex::new(r"^[a-z0-9]+(?:(?:[._]|__|-+)[a-z0-9]+)*$").unwrap()
+});
+static TAG_RE: LazyLock<Regex> = LazyLock::new(||
+{
+    Regex::new(r"^\w[\w.-]{0,127}$").unwrap()
+});
+static DIGEST_RE: LazyLock<Regex> = LazyLock::new(||
+{
+    Regex::new(r"^[A-Za-z][A-Za-z0-9]*(?:[-_+.][A-Za-z][A-Za-z0-9]*)*:[0-9a-fA-F]{32,}$").unwrap()
+});
+static ENV_KEY_RE: LazyLock<Regex> = LazyLock::new(||
+{
+    Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$").unwrap()
+});
+
+/// Why a form field would be refused by the backend, found before submitting.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError
+{
+    Required,
+    NameTooLong,
+    NameInvalidChars,
+    NameHyphenEdge,
+    ImageUppercase,
+    ImageInvalidRegistry,
+    ImageInvalidPath,
+    ImageNameTooLong,
+    ImageInvalidTag,
+    ImageInvalidDigest,
+    EnvMissingSeparator { line: usize },
+    EnvInvalidKey { line: usize },
+    EnvDuplicateKey { line: usize },
+    EnvValueRequired { line: usize },
+    VolumeNotAbsolute,
+    VolumeRoot,
+    VolumeRelativeSegment,
+    VolumeInvalidChars,
+}
+
+impl ValidationError
+{
+    pub fn translation_key(&self) -> &'static str
+    {
+        match self
+        {
+            ValidationError::Required => "create_project.validation_required",
+            ValidationError::NameTooLong => "create_project.validation_name_too_long",
+            ValidationError::NameInvalidChars => "create_project.validation_name_invalid_chars",
+            ValidationError::NameHyphenEdge => "create_project.validation_name_hyphen_edge",
+            ValidationError::ImageUppercase => "create_project.validation_image_uppercase",
+            ValidationError::ImageInvalidRegistry => "create_project.validation_image_invalid_registry",
+            ValidationError::ImageInvalidPath => "create_project.validation_image_invalid_path",
+            ValidationError::ImageNameTooLong => "create_project.validation_image_name_too_long",
+            ValidationError::ImageInvalidTag => "create_project.validation_image_invalid_tag",
+            ValidationError::ImageInvalidDigest => "create_project.validation_image_invalid_digest",
+            ValidationError::EnvMissingSeparator { .. } => "create_project.validation_env_missing_separator",
+            ValidationError::EnvInvalidKey { .. } => "create_project.validation_env_invalid_key",
+            ValidationError::EnvDuplicateKey { .. } => "create_project.validation_env_duplicate_key",
+            ValidationError::EnvValueRequired { .. } => "create_project.validation_env_value_required",
+            ValidationError::VolumeNotAbsolute => "create_project.validation_volume_not_absolute",
+            ValidationError::VolumeRoot => "create_project.validation_volume_root",
+            ValidationError::VolumeRelativeSegment => "create_project.validation_volume_relative_segment",
+            ValidationError::VolumeInvalidChars => "create_project.validation_volume_invalid_chars",
+        }
+    }
+
+    /// 1-based line of the offending `KEY=VALUE` entry, for env var errors.
+    pub fn line(&self) -> Option<usize>
+    {
+        match self
+        {
+            ValidationError::EnvMissingSeparator { line }
+            | ValidationError::EnvInvalidKey { line }
+            | ValidationError::EnvDuplicateKey { line }
+            | ValidationError::EnvValueRequired { line } => Some(*line),
+            _ => None,
+        }
+    }
+}
+
+/// A project name must be a valid DNS label: lowercase letters, digits and
+/// hyphens, not starting or ending with a hyphen, at most 63 characters.
+pub fn validate_project_name(name: &str) -> Result<(), ValidationError>
+{
+    if name.is_empty()
+    {
+        return Err(ValidationError::Required);
+    }
+    if name.len() > MAX_PROJECT_NAME_LEN
+    {
+        return Err(ValidationError::NameTooLong);
+    }
+    if !name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err(ValidationError::NameInvalidChars);
+    }
+    if name.starts_with('-') || name.ends_with('-')
+    {
+        return Err(ValidationError::NameHyphenEdge);
+    }
+    Ok(())
+}
+
+/// Checks `[registry[:port]/]path[:tag][@digest]` against the grammar used by
+/// the Docker distribution reference parser.
+pub fn validate_image_reference(reference: &str) -> Result<(), ValidationError>
+{
+    let reference = reference.trim();
+    if reference.is_empty()
+    {
+        return Err(ValidationError::Required);
+    }
+
+    let (rest, digest) = match reference.split_once('@')
+    {
+        Some((rest, digest)) => (rest, Some(digest)),
+        None => (reference, None),
+    };
+    if let Some(digest) = digest
+        && !DIGEST_RE.is_match(digest)
+    {
+        return Err(ValidationError::ImageInvalidDigest);
+    }
+
+    // A colon after the last slash starts the tag; earlier ones belong to a registry port
+    let last_slash = rest.rfind('/').map_or(0, |i| i + 1);
+    let (name, tag) = match rest[last_slash..].rfind(':')
+    {
+        Some(i) => (&rest[..last_slash + i], Some(&rest[last_slash + i + 1..])),
+        None => (rest, None),
+    };
+    if let Some(tag) = tag
+        && !TAG_RE.is_match(tag)
+    {
+        return Err(ValidationError::ImageInvalidTag);
+    }
+
+    if name.len() > MAX_IMAGE_NAME_LEN
+    {
+        return Err(ValidationError::ImageNameTooLong);
+    }
+
+    let mut components: Vec<&str> = name.split('/').collect();
+    // Same disambiguation as Docker: the first component is a registry only if it looks like a host
+    if components.len() > 1
+    {
+        let first = components[0];
+        if first.contains('.') || first.contains(':') || first == "localhost"
+        {
+            if !REGISTRY_RE.is_match(first)
+            {
+                return Err(ValidationError::ImageInvalidRegistry);
+            }
+            components.remove(0);
+        }
+    }
+
+    for component in components
+    {
+        if PATH_COMPONENT_RE.is_match(component)
+        {
+            continue;
+        }
+        if component.chars().any(|c| c.is_ascii_uppercase()) && PATH_COMPONENT_RE.is_match(&component.to_ascii_lowercase())
+        {
+            return Err(ValidationError::ImageUppercase);
+        }
+        return Err(ValidationError::ImageInvalidPath);
+    }
+    Ok(())
+}
+
+/// POSIX-style name: letters, digits and underscores, not starting with a digit.
+pub fn is_valid_env_var_key(key: &str) -> bool
+{
+    ENV_KEY_RE.is_match(key)
+}
+
+/// Validates the `KEY=VALUE` textarea line by line; blank lines are ignored.
+pub fn validate_env_vars(text: &str) -> Result<(), ValidationError>
+{
+    let mut seen = HashSet::new();
+
+    for (index, line) in text.lines().enumerate()
+    {
+        let line_number = index + 1;
+        let line = line.trim();
+        if line.is_empty()
+        {
+            continue;
+        }
+
+        let Some((key, _)) = line.split_once('=')
+        else
+        {
+            return Err(ValidationError::EnvMissingSeparator { line: line_number });
+        };
+        let key = key.trim();
+
+        if !is_valid_env_var_key(key)
+        {
+            return Err(ValidationError::EnvInvalidKey { line: line_number });
+        }
+        if !seen.insert(key)
+        {
+            return Err(ValidationError::EnvDuplicateKey { line: line_number });
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the `required` keys, whose values were not kept in a draft or a manifest,
+/// have been given a value again.
+pub fn validate_required_env_values(text: &str, required: &[String]) -> Result<(), ValidationError>
+{
+    for (index, line) in text.lines().enumerate()
+    {
+        if let Some((key, value)) = line.trim().split_once('=')
+            && value.trim().is_empty()
+            && required.iter().any(|r| r == key.trim())
+        {
+            return Err(ValidationError::EnvValueRequired { line: index + 1 });
+        }
+    }
+    Ok(())
+}
+
+/// A persistent volume must be an absolute path inside the container, other
+/// than `/` itself, without `.`/`..` segments or characters Docker treats specially.
+pub fn validate_volume_path(path: &str) -> Result<(), ValidationError>
+{
+    let path = path.trim();
+    if !path.starts_with('/')
+    {
+        return Err(ValidationError::VolumeNotAbsolute);
+    }
+    if path.chars().any(|c| c.is_whitespace() || c.is_control() || c == ':' || c == ',')
+    {
+        return Err(ValidationError::VolumeInvalidChars);
+    }
+    if path.split('/').any(|segment| segment == "." || segment == "..")
+    {
+        return Err(ValidationError::VolumeRelativeSegment);
+    }
+    if path.trim_end_matches('/').is_empty()
+    {
+        return Err(ValidationError::VolumeRoot);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn project_name_length_limit()
+    {
+        assert_eq!(validate_project_name(&"a".repeat(63)), Ok(()));
+        assert_eq!(validate_project_name(&"a".repeat(64)), Err(ValidationError::NameTooLong));
+        assert_eq!(validate_project_name(""), Err(ValidationError::Required));
+    }
+
+    #[test]
+    fn project_name_hyphens()
+    {
+        assert_eq!(validate_project_name("my-app-2"), Ok(()));
+        assert_eq!(validate_project_name("-app"), Err(ValidationError::NameHyphenEdge));
+        assert_eq!(validate_project_name("app-"), Err(ValidationError::NameHyphenEdge));
+        assert_eq!(validate_project_name("My_App"), Err(ValidationError::NameInvalidChars));
+    }
+
+    #[test]
+    fn image_reference_registry_port_and_tag()
+    {
+        assert_eq!(validate_image_reference("localhost:5000/a:b"), Ok(()));
+        assert_eq!(validate_image_reference("nginx"), Ok(()));
+        assert_eq!(validate_image_reference("ghcr.io/org/app:1.2.3"), Ok(()));
+    }
+
+    #[test]
+    fn image_reference_uppercase()
+    {
+        assert_eq!(validate_image_reference("ghcr.io/Org/app"), Err(ValidationError::ImageUppercase));
+        // The registry host is case-insensitive
+        assert_eq!(validate_image_reference("GHCR.io/org/app"), Ok(()));
+    }
+
+    #[test]
+    fn image_reference_digest()
+    {
+        let digest = format!("sha256:{}", "a".repeat(64));
+        assert_eq!(validate_image_reference(&format!("name@{}", digest)), Ok(()));
+        assert_eq!(validate_image_reference(&format!("name:latest@{}", digest)), Ok(()));
+        assert_eq!(validate_image_reference("name@sha256:xyz"), Err(ValidationError::ImageInvalidDigest));
+    }
+
+    #[test]
+    fn image_reference_bad_tag()
+    {
+        assert_eq!(validate_image_reference("nginx:-latest"), Err(ValidationError::ImageInvalidTag));
+        assert_eq!(validate_image_reference(&format!("nginx:{}", "a".repeat(129))), Err(ValidationError::ImageInvalidTag));
+        assert_eq!(validate_image_reference("nginx:"), Err(ValidationError::ImageInvalidTag));
+    }
+
+    #[test]
+    fn env_vars_duplicate_key_line()
+    {
+        let text = "A=1\n\nB=2\nA=3";
+        assert_eq!(validate_env_vars(text), Err(ValidationError::EnvDuplicateKey { line: 4 }));
+        assert_eq!(validate_env_vars(text).unwrap_err().line(), Some(4));
+    }
+
+    #[test]
+    fn env_vars_invalid_lines()
+    {
+        assert_eq!(validate_env_vars("A=1\nB"), Err(ValidationError::EnvMissingSeparator { line: 2 }));
+        assert_eq!(validate_env_vars("1A=1"), Err(ValidationError::EnvInvalidKey { line: 1 }));
+        assert_eq!(validate_env_vars("A=b=c\n _B = 2 "), Ok(()));
+    }
+
+    #[test]
+    fn env_vars_required_values()
+    {
+        let required = vec!["SECRET_KEY".to_string()];
+        assert_eq!(validate_required_env_values("DEBUG=\nSECRET_KEY=", &required), Err(ValidationError::EnvValueRequired { line: 2 }));
+        assert_eq!(validate_required_env_values("DEBUG=\nSECRET_KEY=s3cr3t", &required), Ok(()));
+        // A key the user removed is no longer required
+        assert_eq!(validate_required_env_values("DEBUG=", &required), Ok(()));
+    }
+
+    #[test]
+    fn volume_paths()
+    {
+        assert_eq!(validate_volume_path("/data"), Ok(()));
+        assert_eq!(validate_volume_path("/"), Err(ValidationError::VolumeRoot));
+        assert_eq!(validate_volume_path("/a/../b"), Err(ValidationError::VolumeRelativeSegment));
+        assert_eq!(validate_volume_path("/a/./b"), Err(ValidationError::VolumeRelativeSegment));
+        assert_eq!(validate_volume_path("data"), Err(ValidationError::VolumeNotAbsolute));
+        assert_eq!(validate_volume_path("./data"), Err(ValidationError::VolumeNotAbsolute));
+        assert_eq!(validate_volume_path("/my data"), Err(ValidationError::VolumeInvalidChars));
+    }
+}
\ No newline at end of file